@@ -0,0 +1,63 @@
+use std::{env, fs, path::Path};
+
+// Collects every `src/yYYYY/dayNN.rs` and generates a function that registers all of them, so a
+// new day only needs its source file and its `pub mod` in `src/yYYYY.rs` to show up in the
+// runner. The modules themselves are declared in the checked-in code, so rustfmt can find them.
+fn main() {
+    // Relative to the package, so a target directory shared by several checkouts does not keep
+    // watching the sources of another checkout
+    println!("cargo:rerun-if-changed=src");
+    let source_dir = Path::new("src");
+
+    let years = sorted_names(source_dir, |name| {
+        let year = name.strip_prefix('y')?;
+        is_number(year, 4).then(|| name.to_string())
+    });
+
+    let mut generated = String::new();
+    generated += "fn register_days(registry: &mut crate::solver::Registry) {\n";
+    for year in &years {
+        let year_module = source_dir.join(format!("{}.rs", year));
+        let declarations = fs::read_to_string(&year_module).unwrap_or_default();
+        let days = sorted_names(&source_dir.join(year), |name| {
+            let day = name.strip_prefix("day")?.strip_suffix(".rs")?;
+            is_number(day, 2).then(|| format!("day{}", day))
+        });
+        for day in &days {
+            if !declarations.contains(&format!("pub mod {};", day)) {
+                panic!(
+                    "src/{}/{}.rs is not declared, add `pub mod {};` to {}",
+                    year,
+                    day,
+                    day,
+                    year_module.display()
+                );
+            }
+            generated += &format!(
+                "    crate::{}::{}::register(registry, {});\n",
+                year,
+                day,
+                &year[1..]
+            );
+        }
+    }
+    generated += "}\n";
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("days.rs"), generated).expect("Could not write days.rs!");
+}
+
+// The names of all entries in `dir` accepted by `filter`, sorted
+fn sorted_names(dir: &Path, filter: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    let mut names = fs::read_dir(dir)
+        .expect("Could not read source directory!")
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| filter(&entry.file_name().into_string().ok()?))
+        .collect::<Vec<String>>();
+    names.sort();
+    names
+}
+
+fn is_number(text: &str, digits: usize) -> bool {
+    text.len() == digits && text.chars().all(|c| c.is_ascii_digit())
+}
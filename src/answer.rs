@@ -0,0 +1,102 @@
+use std::fmt;
+
+/// The result of a puzzle part. Most puzzles have a plain integer answer, but some need
+/// values beyond i64, a string like a comma separated list, or a rendered grid of letters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    Unsigned(u128),
+    Text(String),
+    MultiLine(Vec<String>),
+}
+
+impl Answer {
+    /// Creates a multi-line answer from text like a rendered grid
+    pub fn multi_line(text: &str) -> Self {
+        Answer::MultiLine(text.lines().map(|s| s.to_string()).collect())
+    }
+
+    pub fn is_multi_line(&self) -> bool {
+        matches!(self, Answer::MultiLine(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::MultiLine(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Integer(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        i64::try_from(value)
+            .map(Answer::Integer)
+            .unwrap_or(Answer::Unsigned(value as u128))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::from(value as u64)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.contains('\n') {
+            Answer::multi_line(&value)
+        } else {
+            Answer::Text(value)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::from(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!("-42", Answer::from(-42).to_string());
+        assert_eq!(
+            "340282366920938463463374607431768211455",
+            Answer::from(u128::MAX).to_string()
+        );
+        assert_eq!(Answer::Integer(7), Answer::from(7_usize));
+        assert_eq!(Answer::Unsigned(u64::MAX as u128), Answer::from(u64::MAX));
+        assert_eq!("4,6,3,5", Answer::from("4,6,3,5").to_string());
+
+        let grid = Answer::from("#..#\n####\n#..#");
+        assert!(grid.is_multi_line());
+        assert_eq!("#..#\n####\n#..#", grid.to_string());
+    }
+}
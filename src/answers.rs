@@ -0,0 +1,213 @@
+use std::{fmt, fs, io, path::PathBuf};
+
+use toml::{Table, Value};
+
+use crate::answer::Answer;
+
+/// Result of comparing an answer with the known answer from the answers file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Correct,
+    Incorrect { expected: String },
+    // The correct answer is not known yet, but the server rejected this one
+    Rejected,
+    Unknown,
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verification::Correct => write!(f, "correct"),
+            Verification::Incorrect { expected } if expected.contains('\n') => {
+                write!(f, "incorrect, expected\n{}\n", expected)
+            }
+            Verification::Incorrect { expected } => write!(f, "incorrect, expected {}", expected),
+            Verification::Rejected => write!(f, "rejected"),
+            Verification::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Verification {
+    /// Short name of the status without the expected answer
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verification::Correct => "correct",
+            Verification::Incorrect { .. } => "incorrect",
+            Verification::Rejected => "rejected",
+            Verification::Unknown => "unknown",
+        }
+    }
+}
+
+/// The confirmed answers of the real puzzle inputs, stored as TOML with one table per day. The
+/// key of a day is its id, each `/` in it starts a nested table. Answers which the server
+/// rejected are kept as well, so they are not submitted again:
+///
+/// ```toml
+/// [2024.day01]
+/// part1 = "1189304"
+/// part2 = "24349736"
+/// part2_rejected = ["24349735"]
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnswerStore {
+    path: PathBuf,
+    table: Table,
+}
+
+impl AnswerStore {
+    /// Loads the answers file, a missing file is an empty store. Any other read error is an
+    /// error, saving an empty store would overwrite the answers in the file otherwise.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, String> {
+        let path = path.into();
+        let table = match fs::read_to_string(&path) {
+            Ok(content) => content
+                .parse::<Table>()
+                .map_err(|e| format!("Could not parse {}: {}", path.display(), e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Table::new(),
+            Err(e) => return Err(format!("Could not read {}: {}", path.display(), e)),
+        };
+        Ok(Self { path, table })
+    }
+
+    // The table of a key like `2024/day01`
+    fn entry(&self, key: &str) -> Option<&Table> {
+        let mut table = &self.table;
+        for segment in key.split('/') {
+            table = table.get(segment)?.as_table()?;
+        }
+        Some(table)
+    }
+
+    // The table of a key, created if it does not exist yet
+    fn entry_mut(&mut self, key: &str) -> Option<&mut Table> {
+        let mut table = &mut self.table;
+        for segment in key.split('/') {
+            let value = table
+                .entry(segment)
+                .or_insert_with(|| Value::Table(Table::new()));
+            let Value::Table(nested) = value else {
+                return None;
+            };
+            table = nested;
+        }
+        Some(table)
+    }
+
+    pub fn expected(&self, key: &str, part: u8) -> Option<&str> {
+        self.entry(key)?.get(&format!("part{}", part))?.as_str()
+    }
+
+    /// Whether the server rejected the answer before
+    pub fn is_rejected(&self, key: &str, part: u8, answer: &Answer) -> bool {
+        let answer = answer.to_string();
+        self.entry(key)
+            .and_then(|table| table.get(&format!("part{}_rejected", part))?.as_array())
+            .is_some_and(|rejected| rejected.iter().any(|r| r.as_str() == Some(&answer)))
+    }
+
+    pub fn verify(&self, key: &str, part: u8, answer: &Answer) -> Verification {
+        match self.expected(key, part) {
+            Some(expected) if expected == answer.to_string() => Verification::Correct,
+            Some(expected) => Verification::Incorrect {
+                expected: expected.to_string(),
+            },
+            None if self.is_rejected(key, part, answer) => Verification::Rejected,
+            None => Verification::Unknown,
+        }
+    }
+
+    /// Stores the answer of a part, returns false if a different answer is already known or
+    /// the answer was rejected
+    pub fn record(&mut self, key: &str, part: u8, answer: &Answer) -> bool {
+        match self.verify(key, part, answer) {
+            Verification::Correct => return true,
+            Verification::Incorrect { .. } | Verification::Rejected => return false,
+            Verification::Unknown => {}
+        }
+        let Some(table) = self.entry_mut(key) else {
+            return false;
+        };
+        table.insert(format!("part{}", part), Value::String(answer.to_string()));
+        true
+    }
+
+    /// Remembers an answer the server rejected
+    pub fn reject(&mut self, key: &str, part: u8, answer: &Answer) {
+        if self.is_rejected(key, part, answer) {
+            return;
+        }
+        if let Some(table) = self.entry_mut(key) {
+            let rejected = table
+                .entry(format!("part{}_rejected", part))
+                .or_insert_with(|| Value::Array(vec![]));
+            if let Value::Array(rejected) = rejected {
+                rejected.push(Value::String(answer.to_string()));
+            }
+        }
+    }
+
+    /// Writes all answers back to the file. The TOML is written from scratch, so comments and
+    /// the formatting of the file are not kept.
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, self.table.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_and_record() {
+        let mut store = AnswerStore::load("does/not/exist.toml").unwrap();
+        store.table = "[2024.day01]\npart1 = \"11\"\n".parse::<Table>().unwrap();
+
+        assert_eq!(
+            Verification::Correct,
+            store.verify("2024/day01", 1, &Answer::from(11))
+        );
+        assert_eq!(
+            Verification::Incorrect {
+                expected: "11".to_string()
+            },
+            store.verify("2024/day01", 1, &Answer::from(12))
+        );
+        assert_eq!(
+            Verification::Unknown,
+            store.verify("2024/day01", 2, &Answer::from(31))
+        );
+
+        assert!(store.record("2024/day01", 2, &Answer::from(31)));
+        assert!(!store.record("2024/day01", 1, &Answer::from(12)));
+        assert!(store.record("2023/day02", 1, &Answer::from("#.#\n.#.")));
+        assert_eq!(Some("31"), store.expected("2024/day01", 2));
+        assert_eq!(Some("11"), store.expected("2024/day01", 1));
+        assert_eq!(None, store.expected("2023/day01", 1));
+
+        store.reject("2024/day03", 1, &Answer::from(7));
+        store.reject("2024/day03", 1, &Answer::from(9));
+        assert_eq!(
+            Verification::Rejected,
+            store.verify("2024/day03", 1, &Answer::from(9))
+        );
+        assert!(!store.record("2024/day03", 1, &Answer::from(7)));
+        assert!(store.record("2024/day03", 1, &Answer::from(8)));
+
+        // Multi-line answers survive a round trip through the file format
+        let reloaded = store.table.to_string().parse::<Table>().unwrap();
+        store.table = reloaded;
+        assert_eq!(
+            Verification::Correct,
+            store.verify("2023/day02", 1, &Answer::from("#.#\n.#."))
+        );
+        assert!(store.is_rejected("2024/day03", 1, &Answer::from(7)));
+
+        // Only a missing file is an empty store, a file which can not be read is an error
+        assert!(AnswerStore::load(std::env::temp_dir()).is_err());
+    }
+}
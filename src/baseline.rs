@@ -0,0 +1,265 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::Write,
+    path::Path,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::bench::BenchResult;
+
+const HEADER: &str =
+    "commit,timestamp,day,variant,phase,samples,mean_ns,std_dev_ns,median_ns,min_ns,max_ns";
+
+// Welch's t statistic above which a slowdown is considered significant,
+// roughly 99% confidence for the sample counts we use
+const SIGNIFICANT_T: f64 = 2.576;
+
+/// One stored benchmark result, all times are in nanoseconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchRecord {
+    pub commit: String,
+    pub timestamp: u64,
+    pub day: String,
+    pub variant: String,
+    pub phase: String,
+    pub samples: usize,
+    pub mean: f64,
+    pub std_dev: f64,
+    pub median: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl BenchRecord {
+    pub fn from_result(result: &BenchResult, commit: &str, timestamp: u64) -> Self {
+        Self {
+            commit: commit.to_string(),
+            timestamp,
+            day: result.day.clone(),
+            variant: result.variant.to_string(),
+            phase: result.phase.to_string(),
+            samples: result.timing.len(),
+            mean: result.timing.mean().as_nanos() as f64,
+            std_dev: result.timing.std_dev().as_nanos() as f64,
+            median: result.timing.median().as_nanos() as f64,
+            min: result.timing.min().as_nanos() as f64,
+            max: result.timing.max().as_nanos() as f64,
+        }
+    }
+
+    fn key(&self) -> (String, String, String) {
+        (self.day.clone(), self.variant.clone(), self.phase.clone())
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{:.0},{:.0},{:.0},{:.0},{:.0}",
+            self.commit,
+            self.timestamp,
+            self.day,
+            self.variant,
+            self.phase,
+            self.samples,
+            self.mean,
+            self.std_dev,
+            self.median,
+            self.min,
+            self.max
+        )
+    }
+
+    fn from_csv(line: &str) -> Option<Self> {
+        let fields = line.split(',').collect::<Vec<&str>>();
+        if fields.len() != 11 {
+            return None;
+        }
+        Some(Self {
+            commit: fields[0].to_string(),
+            timestamp: fields[1].parse().ok()?,
+            day: fields[2].to_string(),
+            variant: fields[3].to_string(),
+            phase: fields[4].to_string(),
+            samples: fields[5].parse().ok()?,
+            mean: fields[6].parse().ok()?,
+            std_dev: fields[7].parse().ok()?,
+            median: fields[8].parse().ok()?,
+            min: fields[9].parse().ok()?,
+            max: fields[10].parse().ok()?,
+        })
+    }
+}
+
+/// A phase which got significantly slower than in the baseline
+#[derive(Debug, Clone)]
+pub struct Regression {
+    pub baseline: BenchRecord,
+    pub current: BenchRecord,
+}
+
+impl Regression {
+    /// Relative change of the mean, e.g. 0.25 for 25% slower
+    pub fn change(&self) -> f64 {
+        self.current.mean / self.baseline.mean - 1.0
+    }
+}
+
+/// Short hash of the checked out commit, `unknown` outside of a git repository
+pub fn current_commit() -> String {
+    Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Reads all records from a CSV file, a missing file is an empty baseline
+pub fn read_records(path: &Path) -> Vec<BenchRecord> {
+    fs::read_to_string(path)
+        .map(|content| {
+            content
+                .lines()
+                .skip(1)
+                .filter_map(BenchRecord::from_csv)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn write_records(path: &Path, records: &[BenchRecord]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut content = HEADER.to_string() + "\n";
+    for record in records {
+        content += &(record.to_csv() + "\n");
+    }
+    fs::write(path, content)
+}
+
+/// Appends records to a CSV file, which keeps the history of all benchmark runs
+pub fn append_records(path: &Path, records: &[BenchRecord]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let is_new = !path.exists();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for record in records {
+        writeln!(file, "{}", record.to_csv())?;
+    }
+    Ok(())
+}
+
+/// Replaces the baseline entries of all benchmarked phases and keeps the others
+pub fn merge_records(baseline: &[BenchRecord], current: &[BenchRecord]) -> Vec<BenchRecord> {
+    let mut merged = baseline
+        .iter()
+        .filter(|b| !current.iter().any(|c| c.key() == b.key()))
+        .cloned()
+        .collect::<Vec<_>>();
+    merged.extend(current.iter().cloned());
+    merged
+}
+
+/// Finds all phases which are slower than the baseline by more than `threshold` (relative
+/// change of the mean) and where the slowdown is statistically significant by Welch's t-test
+pub fn find_regressions(
+    baseline: &[BenchRecord],
+    current: &[BenchRecord],
+    threshold: f64,
+) -> Vec<Regression> {
+    let baseline = baseline
+        .iter()
+        .map(|b| (b.key(), b))
+        .collect::<HashMap<_, _>>();
+
+    current
+        .iter()
+        .filter_map(|current| {
+            let baseline = *baseline.get(&current.key())?;
+            let standard_error = (baseline.std_dev.powi(2) / baseline.samples.max(1) as f64
+                + current.std_dev.powi(2) / current.samples.max(1) as f64)
+                .sqrt();
+            let difference = current.mean - baseline.mean;
+            let significant = if standard_error > 0.0 {
+                difference / standard_error > SIGNIFICANT_T
+            } else {
+                difference > 0.0
+            };
+            let regression = Regression {
+                baseline: baseline.clone(),
+                current: current.clone(),
+            };
+            (significant && regression.change() > threshold).then_some(regression)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(phase: &str, mean: f64, std_dev: f64) -> BenchRecord {
+        BenchRecord {
+            commit: "abc1234".to_string(),
+            timestamp: 1734567890,
+            day: "2024/day06".to_string(),
+            variant: "default".to_string(),
+            phase: phase.to_string(),
+            samples: 50,
+            mean,
+            std_dev,
+            median: mean,
+            min: mean - std_dev,
+            max: mean + std_dev,
+        }
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let original = record("part 1", 1500.0, 20.0);
+        let line = original.to_csv();
+        assert_eq!(Some(original), BenchRecord::from_csv(&line));
+        assert_eq!(None, BenchRecord::from_csv(HEADER));
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let baseline = vec![
+            record("parse", 1000.0, 10.0),
+            record("part 1", 1000.0, 10.0),
+        ];
+        // part 1 is 50% slower, parse only differs within the noise
+        let current = vec![
+            record("parse", 1002.0, 10.0),
+            record("part 1", 1500.0, 10.0),
+        ];
+
+        let regressions = find_regressions(&baseline, &current, 0.05);
+        assert_eq!(1, regressions.len());
+        assert_eq!("part 1", regressions[0].current.phase);
+        assert!((regressions[0].change() - 0.5).abs() < 1e-9);
+
+        // A new baseline only replaces the benchmarked phases
+        let merged = merge_records(&baseline, &current[1..]);
+        assert_eq!(2, merged.len());
+        assert!(merged.contains(&current[1]));
+        assert!(merged.contains(&baseline[0]));
+    }
+}
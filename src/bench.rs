@@ -0,0 +1,140 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::SolveError,
+    solver::DynSolver,
+    timing::{format_duration, Timing},
+};
+
+// Slow phases get fewer samples, but never less than this
+const MIN_SAMPLES: usize = 10;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warm_up: Duration,
+    // Target for the total measurement time of one phase
+    pub measurement: Duration,
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(500),
+            measurement: Duration::from_secs(2),
+            samples: 50,
+        }
+    }
+}
+
+/// Benchmark statistics of one phase of one solver variant
+#[derive(Debug, Clone)]
+pub struct BenchResult {
+    pub day: String,
+    pub variant: &'static str,
+    pub phase: &'static str,
+    // Time per iteration of every sample, with outliers already removed
+    pub timing: Timing,
+    pub outliers: usize,
+}
+
+/// Measures `function` by running it for the warm-up time first, then collecting samples of
+/// (possibly) many iterations each, so very fast functions are not dominated by timer overhead
+pub fn bench_function<T>(config: &BenchConfig, mut function: impl FnMut() -> T) -> (Timing, usize) {
+    let mut warm_up_iterations = 0_u32;
+    let start = Instant::now();
+    while warm_up_iterations == 0 || start.elapsed() < config.warm_up {
+        black_box(function());
+        warm_up_iterations += 1;
+    }
+    let estimate = start.elapsed() / warm_up_iterations;
+
+    let sample_time = config.measurement / config.samples as u32;
+    let iterations = (sample_time.as_nanos() / estimate.as_nanos().max(1)).max(1) as u32;
+    let samples = if iterations == 1 {
+        (config.measurement.as_nanos() / estimate.as_nanos().max(1)) as usize
+    } else {
+        config.samples
+    }
+    .clamp(MIN_SAMPLES.min(config.samples), config.samples);
+
+    let mut timing = Timing::default();
+    for _ in 0..samples {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            black_box(function());
+        }
+        timing.add(start.elapsed() / iterations);
+    }
+    timing.reject_outliers()
+}
+
+/// Benchmarks parsing and both parts of a solver on the given puzzle input, which is run once
+/// first so a solver that fails on it is not benchmarked at all
+pub fn bench_solver(
+    solver: &'static dyn DynSolver,
+    input_data: &str,
+    config: &BenchConfig,
+) -> Result<Vec<BenchResult>, SolveError> {
+    let result = |phase, (timing, outliers)| BenchResult {
+        day: solver.id(),
+        variant: solver.variant(),
+        phase,
+        timing,
+        outliers,
+    };
+
+    let parsed = solver.parse(input_data)?;
+    solver.part1(&parsed)?;
+    solver.part2(&parsed)?;
+    Ok(vec![
+        result("parse", bench_function(config, || solver.parse(input_data))),
+        result("part 1", bench_function(config, || solver.part1(&parsed))),
+        result("part 2", bench_function(config, || solver.part2(&parsed))),
+    ])
+}
+
+pub fn print_results(results: &[BenchResult]) {
+    println!(
+        "{:<12} {:<24} {:<8} {:>12} {:>12} {:>12} {:>12} {:>12} {:>9}",
+        "Day", "Variant", "Phase", "Mean", "Std dev", "Median", "Min", "Max", "Outliers"
+    );
+    for result in results {
+        println!(
+            "{:<12} {:<24} {:<8} {:>12} {:>12} {:>12} {:>12} {:>12} {:>9}",
+            result.day.to_uppercase(),
+            result.variant,
+            result.phase,
+            format_duration(result.timing.mean()),
+            format_duration(result.timing.std_dev()),
+            format_duration(result.timing.median()),
+            format_duration(result.timing.min()),
+            format_duration(result.timing.max()),
+            format!(
+                "{}/{}",
+                result.outliers,
+                result.outliers + result.timing.len()
+            )
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_function() {
+        let config = BenchConfig {
+            warm_up: Duration::from_millis(1),
+            measurement: Duration::from_millis(20),
+            samples: 20,
+        };
+        let (timing, outliers) = bench_function(&config, || (0..100).sum::<u64>());
+        assert_eq!(20, timing.len() + outliers);
+        assert!(timing.min() <= timing.median() && timing.median() <= timing.max());
+    }
+}
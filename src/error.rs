@@ -0,0 +1,140 @@
+use std::{fmt, str::FromStr};
+
+/// Why a solver could not produce an answer. Solvers create errors with `at` and `unsolvable`,
+/// the year and day are filled in by the registry when the error leaves the solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// Malformed input, line and column are 1-based
+    Parse {
+        year: u16,
+        day: u8,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed, but has no solution, e.g. a guard walking in a loop
+    Unsolvable { year: u16, day: u8, message: String },
+}
+
+impl SolveError {
+    /// An error at the start of `fragment`, which must be a slice of `input`
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let (line, column) = locate(input, fragment);
+        SolveError::Parse {
+            year: 0,
+            day: 0,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        SolveError::Unsolvable {
+            year: 0,
+            day: 0,
+            message: message.into(),
+        }
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        match &mut self {
+            SolveError::Parse { day: d, .. } | SolveError::Unsolvable { day: d, .. } => *d = day,
+        }
+        self
+    }
+
+    pub fn with_year(mut self, year: u16) -> Self {
+        match &mut self {
+            SolveError::Parse { year: y, .. } | SolveError::Unsolvable { year: y, .. } => *y = year,
+        }
+        self
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse {
+                year,
+                day,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "{}: invalid input at line {}, column {}: {}",
+                day_id(*year, *day),
+                line,
+                column,
+                message
+            ),
+            SolveError::Unsolvable { year, day, message } => {
+                write!(f, "{}: no solution: {}", day_id(*year, *day), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+// The id of the day like `2024/day06`, only `day06` if the year is not known
+fn day_id(year: u16, day: u8) -> String {
+    if year == 0 {
+        format!("day{:02}", day)
+    } else {
+        format!("{}/day{:02}", year, day)
+    }
+}
+
+/// Parses `token`, which must be a slice of `input`, and reports its position on failure
+pub fn parse_at<T: FromStr>(input: &str, token: &str) -> Result<T, SolveError> {
+    token
+        .parse::<T>()
+        .map_err(|_| SolveError::at(input, token, format!("`{}` is not a valid number", token)))
+}
+
+// Line and column of the start of `fragment` within `input`, falls back to the end of the
+// input if the fragment is not part of it
+fn locate(input: &str, fragment: &str) -> (usize, usize) {
+    let offset = (fragment.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset <= input.len())
+        .unwrap_or(input.len());
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_at() {
+        let input = "3   4\n4   x\n";
+        let token = input.lines().nth(1).unwrap().split("   ").nth(1).unwrap();
+
+        assert_eq!(Ok(4), parse_at::<i64>(input, &input[6..7]));
+        let error = parse_at::<i64>(input, token).unwrap_err().with_day(1);
+        assert_eq!(
+            SolveError::Parse {
+                year: 0,
+                day: 1,
+                line: 2,
+                column: 5,
+                message: "`x` is not a valid number".to_string()
+            },
+            error
+        );
+        assert_eq!(
+            "day01: invalid input at line 2, column 5: `x` is not a valid number",
+            error.to_string()
+        );
+        assert_eq!(
+            "2024/day01: invalid input at line 2, column 5: `x` is not a valid number",
+            error.with_year(2024).to_string()
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{answers::AnswerStore, solver::DynSolver};
+
+/// Where the example inputs live, one directory per year and day
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Examples are part of the repository, the real puzzle inputs are personal and not checked in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Example,
+    Real,
+}
+
+/// An example input of a day with its expected answers. The examples of a day are stored as
+/// `examples/YYYY/dayNN/<name>.txt`, their answers in `examples/YYYY/dayNN/answers.toml` with
+/// one table per example:
+///
+/// ```toml
+/// [example]
+/// part1 = "143"
+/// part2 = "123"
+/// ```
+///
+/// Parts without an expected answer are not checked. The real inputs are fixtures as well, with
+/// the answers from `data/answers.toml`.
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    // The id of the day, e.g. `2024/day03`
+    pub day: String,
+    pub name: String,
+    pub kind: InputKind,
+    pub input: PathBuf,
+    pub expected: [Option<String>; 2],
+}
+
+impl Fixture {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        self.expected.get(part as usize - 1)?.as_deref()
+    }
+
+    /// Reads the input, or returns why the fixture has to be skipped: a real input which is
+    /// not available on this machine or an example which was not filled in yet
+    pub fn load(&self) -> Result<String, String> {
+        let skipped = |reason| format!("{}/{}: {}, skipped", self.day, self.name, reason);
+        match (fs::read_to_string(&self.input), self.kind) {
+            (Ok(input), InputKind::Example) if input.trim().is_empty() => {
+                Err(skipped("empty example"))
+            }
+            (Ok(input), _) => Ok(input),
+            (Err(_), InputKind::Real) => Err(skipped("input not available")),
+            (Err(e), InputKind::Example) => Err(skipped(&format!("could not read example: {}", e))),
+        }
+    }
+
+    /// Runs the parts with an expected answer and describes every mismatch or error
+    pub fn check(&self, solver: &dyn DynSolver, input: &str) -> Vec<String> {
+        let name = format!("{}/{} [{}]", self.day, self.name, solver.variant());
+        let parsed = match solver.parse(input) {
+            Ok(parsed) => parsed,
+            Err(error) => return vec![format!("{}: {}", name, error)],
+        };
+        let mut failures = vec![];
+        for part in [1, 2] {
+            let Some(expected) = self.expected(part) else {
+                continue;
+            };
+            let answer = match part {
+                1 => solver.part1(&parsed),
+                _ => solver.part2(&parsed),
+            };
+            match answer {
+                Ok(answer) if answer.to_string() == expected => {}
+                Ok(answer) => failures.push(format!(
+                    "{} part {}: expected {}, got {}",
+                    name, part, expected, answer
+                )),
+                Err(error) => failures.push(format!("{} part {}: {}", name, part, error)),
+            }
+        }
+        failures
+    }
+}
+
+/// The real inputs `data/YYYY/dayNN.txt` of the given day ids with the answers stored in the
+/// answers file, whether the inputs exist or not
+pub fn real_inputs<'a>(
+    data_dir: &Path,
+    answers: &AnswerStore,
+    days: impl IntoIterator<Item = &'a str>,
+) -> Vec<Fixture> {
+    days.into_iter()
+        .map(|day| Fixture {
+            day: day.to_string(),
+            name: "input".to_string(),
+            kind: InputKind::Real,
+            input: data_dir.join(format!("{}.txt", day)),
+            expected: [1, 2].map(|part| answers.expected(day, part).map(str::to_string)),
+        })
+        .collect()
+}
+
+/// Finds all examples below `examples_dir`, ordered by year, day and name
+pub fn discover(examples_dir: &Path) -> Result<Vec<Fixture>, String> {
+    let mut fixtures = vec![];
+    for year_dir in sorted_entries(examples_dir)? {
+        let Some(year) = file_name(&year_dir).filter(|name| is_number(name, 4)) else {
+            continue;
+        };
+        for day_dir in sorted_entries(&year_dir)? {
+            let Some(day) = file_name(&day_dir).filter(|name| is_day_key(name)) else {
+                continue;
+            };
+            discover_day(&format!("{}/{}", year, day), &day_dir, &mut fixtures)?;
+        }
+    }
+    Ok(fixtures)
+}
+
+fn discover_day(id: &str, day_dir: &Path, fixtures: &mut Vec<Fixture>) -> Result<(), String> {
+    let answers = AnswerStore::load(day_dir.join("answers.toml"))?;
+    for input in sorted_entries(day_dir)? {
+        if input.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+        let Some(name) = input.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        fixtures.push(Fixture {
+            day: id.to_string(),
+            name: name.to_string(),
+            kind: InputKind::Example,
+            expected: [1, 2].map(|part| answers.expected(name, part).map(str::to_string)),
+            input,
+        });
+    }
+    Ok(())
+}
+
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut entries = fs::read_dir(dir)
+        .map_err(|e| format!("Could not read {}: {}", dir.display(), e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    entries.sort();
+    Ok(entries)
+}
+
+fn file_name(path: &Path) -> Option<String> {
+    path.file_name()?.to_str().map(str::to_string)
+}
+
+// `dayNN` with exactly two digits
+fn is_day_key(name: &str) -> bool {
+    name.strip_prefix("day")
+        .is_some_and(|day| is_number(day, 2))
+}
+
+fn is_number(text: &str, digits: usize) -> bool {
+    text.len() == digits && text.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discover() {
+        let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR);
+        let fixtures = discover(&examples_dir).unwrap();
+
+        let day03 = fixtures
+            .iter()
+            .filter(|f| f.day == "2024/day03")
+            .collect::<Vec<_>>();
+        assert_eq!(2, day03.len());
+        assert_eq!("example1", day03[0].name);
+        assert_eq!(Some("161"), day03[0].expected(1));
+        assert_eq!(None, day03[0].expected(2));
+        assert_eq!(Some("48"), day03[1].expected(2));
+        assert!(fixtures.windows(2).all(|w| w[0].day <= w[1].day));
+        assert!(day03[0].load().unwrap().starts_with("xmul(2,4)"));
+    }
+
+    #[test]
+    fn test_missing_real_input() {
+        let answers = AnswerStore::load("does/not/exist.toml").unwrap();
+        let fixtures = real_inputs(Path::new("does/not/exist"), &answers, ["2024/day05"]);
+        assert_eq!(InputKind::Real, fixtures[0].kind);
+        assert_eq!(
+            PathBuf::from("does/not/exist/2024/day05.txt"),
+            fixtures[0].input
+        );
+        assert_eq!(None, fixtures[0].expected(1));
+        assert_eq!(
+            Err("2024/day05/input: input not available, skipped".to_string()),
+            fixtures[0].load()
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::{
+    fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::provider::default_provider;
+
+/// Where the puzzle input of a run comes from. The runner reads it once and hands the contents
+/// to the solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // The personal input of a day, from `data/` or downloaded
+    Puzzle { year: u16, day: u8 },
+    File(PathBuf),
+    Stdin,
+    // Given on the command line with `--input-text`
+    Text(String),
+}
+
+impl InputSource {
+    /// The personal input of a day, which is cached in `data/YYYY/dayNN.txt`
+    pub fn for_day(year: u16, day: u8) -> Self {
+        InputSource::Puzzle { year, day }
+    }
+
+    /// `-` reads from stdin, everything else is a file path
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            InputSource::Puzzle { year, day } => default_provider().input(*year, *day),
+            InputSource::File(path) => fs::read_to_string(path)
+                .map_err(|e| format!("Could not read {}: {}", path.display(), e)),
+            InputSource::Stdin => {
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .map_err(|e| format!("Could not read stdin: {}", e))?;
+                Ok(content)
+            }
+            InputSource::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Puzzle { year, day } => {
+                write!(
+                    f,
+                    "{}",
+                    default_provider().cache_path(*year, *day).display()
+                )
+            }
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Text(_) => write!(f, "<input-text>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            "data/2024/day06.txt",
+            InputSource::for_day(2024, 6).to_string()
+        );
+
+        let text = InputSource::Text("125 17".to_string());
+        assert_eq!(Ok("125 17".to_string()), text.read());
+        assert!(InputSource::from_arg("does/not/exist.txt").read().is_err());
+    }
+}
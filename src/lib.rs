@@ -0,0 +1,49 @@
+//! Solutions to Advent of Code as a library: the solvers of all days and years, the registry to
+//! look them up and the runner, plus the shared grid utilities for use in other tools.
+//!
+//! ```
+//! let solver = advent2024::registry().get(2024, "day01").unwrap();
+//! let parsed = solver.parse("3   4\n4   3\n2   5").unwrap();
+//! assert_eq!("3", solver.part1(&parsed).unwrap().to_string());
+//! ```
+
+pub mod answer;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod error;
+pub mod fixtures;
+pub mod input;
+pub mod parallel;
+pub mod provider;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod selection;
+pub mod shared_objects;
+pub mod solver;
+pub mod submit;
+// Only compiled by the tests, so changes of the solver API which break the template are noticed
+#[cfg(test)]
+mod template;
+pub mod timing;
+pub mod watch;
+pub mod y2024;
+
+use once_cell::sync::Lazy;
+use solver::Registry;
+
+// Generated by build.rs, contains the `register_days` function which registers every
+// `src/yYYYY/dayNN.rs`
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+static REGISTRY: Lazy<Registry> = Lazy::new(|| {
+    let mut registry = Registry::default();
+    register_days(&mut registry);
+    registry
+});
+
+/// All solvers of the crate
+pub fn registry() -> &'static Registry {
+    &REGISTRY
+}
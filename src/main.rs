@@ -1,107 +1,238 @@
-#![feature(array_chunks)]
-#![feature(test)]
-mod my_io;
-mod shared_objects;
-use std::{collections::HashMap, env, fs};
+use std::{
+    env, fmt,
+    path::{Path, PathBuf},
+    process,
+    time::Duration,
+};
 
-use once_cell::sync::Lazy;
+use advent2024::{
+    answers::AnswerStore,
+    bench::BenchConfig,
+    input::InputSource,
+    registry,
+    report::Format,
+    runner::{self, BaselineOptions, Options, Parts, ANSWERS_FILE},
+    scaffold,
+    selection::{parse_day, DaySelection},
+    watch,
+};
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
+// Removes a flag from the arguments and returns whether it was present
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    if let Some(index) = args.iter().position(|a| a == flag) {
+        args.remove(index);
+        true
+    } else {
+        false
+    }
+}
 
-static DAY_MAP: Lazy<HashMap<&'static str, for<'a> fn(&'a String) -> (i64, i64)>> =
-    Lazy::new(|| {
-        let mut m: HashMap<&'static str, for<'a> fn(&'a String) -> (i64, i64)> = HashMap::new();
-        m.insert("day01", day01::solve);
-        m.insert("day02", day02::solve);
-        m.insert("day03", day03::solve);
-        m.insert("day04", day04::solve);
-        m.insert("day05", day05::solve);
-        m.insert("day06", day06::solve);
-        m.insert("day07", day07::solve);
-        m.insert("day08", day08::solve);
-        m.insert("day09", day09::solve);
-        m.insert("day10", day10::solve);
-        m.insert("day11", day11::solve);
-        // m.insert("day12", day12::solve);
-        // m.insert("day13", day13::solve);
-        // m.insert("day14", day14::solve);
-        // m.insert("day15", day15::solve);
-        // m.insert("day16", day16::solve);
-        // m.insert("day17", day17::solve);
-        // m.insert("day18", day18::solve);
-        // m.insert("day19", day19::solve);
-        // m.insert("day20", day20::solve);
-        // m.insert("day21", day21::solve);
-        // m.insert("day22", day22::solve);
-        // m.insert("day23", day23::solve);
-        // m.insert("day24", day24::solve);
-        // m.insert("day25", day25::solve);
-        m
-    });
+// Removes a flag and the value following it from the arguments
+fn take_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, String> {
+    let Some(index) = args.iter().position(|a| a == flag) else {
+        return Ok(None);
+    };
+    args.remove(index);
+    if index < args.len() {
+        Ok(Some(args.remove(index)))
+    } else {
+        Err(format!("{} must be followed by a value!", flag))
+    }
+}
 
-fn run(day: &String, input: &String) {
-    let part_1;
-    let part_2;
+// Removes a flag and its value, which must be a positive number, from the arguments
+fn take_number(args: &mut Vec<String>, flag: &str) -> Result<Option<usize>, String> {
+    take_value(args, flag)?
+        .map(|value| {
+            value
+                .parse::<usize>()
+                .ok()
+                .filter(|&n| n > 0)
+                .ok_or_else(|| format!("{} must be followed by a positive number!", flag))
+        })
+        .transpose()
+}
 
-    if let Some(day_function) = DAY_MAP.get(day.as_str()) {
-        (part_1, part_2) = day_function(input);
-    } else {
-        println!("Day {} not implemented yet", day);
-        return;
+// Removes the runner flags (`--part 1|2`, `--parse-only`, `--repeat N`, `--record`, `--jobs N`,
+// `--format text|json|csv`)
+fn parse_options(args: &mut Vec<String>) -> Result<Options, String> {
+    let mut options = Options {
+        record: take_flag(args, "--record"),
+        jobs: take_number(args, "--jobs")?.unwrap_or(1),
+        ..Options::default()
+    };
+    if let Some(format) = take_value(args, "--format")? {
+        options.format = Format::parse(&format)?;
+    }
+    if take_flag(args, "--parse-only") {
+        options.parts = Parts::ParseOnly;
+    }
+    if let Some(part) = take_value(args, "--part")? {
+        options.parts = match part.as_str() {
+            "1" => Parts::Part1,
+            "2" => Parts::Part2,
+            _ => return Err("--part must be followed by 1 or 2!".to_string()),
+        };
+    }
+    if let Some(repeat) = take_number(args, "--repeat")? {
+        options.repeat = repeat;
+    }
+    Ok(options)
+}
+
+// Removes `--threads N` and sizes the global rayon pool accordingly, which is shared by all
+// solvers that use rayon internally, also when several days run concurrently
+fn configure_thread_pool(args: &mut Vec<String>) -> Result<(), String> {
+    if let Some(threads) = take_number(args, "--threads")? {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .expect("Could not configure the thread pool!");
+    }
+    Ok(())
+}
+
+// Removes the benchmark flags (`--samples N`, `--warm-up MS`, `--measurement MS`)
+fn parse_bench_config(args: &mut Vec<String>) -> Result<BenchConfig, String> {
+    let mut config = BenchConfig::default();
+    if let Some(samples) = take_number(args, "--samples")? {
+        config.samples = samples;
+    }
+    if let Some(warm_up) = take_number(args, "--warm-up")? {
+        config.warm_up = Duration::from_millis(warm_up as u64);
+    }
+    if let Some(measurement) = take_number(args, "--measurement")? {
+        config.measurement = Duration::from_millis(measurement as u64);
     }
-    println!(
-        "{}: Part 1: {}, Part 2: {}",
-        day.to_uppercase(),
-        part_1,
-        part_2
-    );
+    Ok(config)
 }
+
+// Removes the baseline flags (`--baseline PATH`, `--save-baseline`, `--threshold PERCENT`)
+fn parse_baseline_options(args: &mut Vec<String>) -> Result<BaselineOptions, String> {
+    let baseline = take_value(args, "--baseline")?.unwrap_or("benchmarks/baseline.csv".into());
+    let threshold = take_value(args, "--threshold")?
+        .map(|t| {
+            t.parse::<f64>()
+                .map_err(|_| "--threshold must be followed by a percentage!".to_string())
+        })
+        .transpose()?
+        .unwrap_or(5.0);
+    Ok(BaselineOptions {
+        baseline: PathBuf::from(baseline),
+        history: PathBuf::from("benchmarks/history.csv"),
+        save: take_flag(args, "--save-baseline"),
+        threshold: threshold / 100.0,
+    })
+}
+
+// Prints the error and exits, used for invalid command line arguments
+fn exit_with(error: impl fmt::Display) -> ! {
+    println!("{}", error);
+    process::exit(1);
+}
+
+// The value of a parsed argument, exits with the error message if it is invalid
+fn or_exit<T>(result: Result<T, String>) -> T {
+    result.unwrap_or_else(|error| exit_with(error))
+}
+
 fn main() {
-    let args = env::args().collect::<Vec<String>>();
+    let mut args = env::args().collect::<Vec<String>>();
+    or_exit(configure_thread_pool(&mut args));
+    let options = or_exit(parse_options(&mut args));
+    let bench_config = or_exit(parse_bench_config(&mut args));
+    let baseline_options = or_exit(parse_baseline_options(&mut args));
+    let input_text = or_exit(take_value(&mut args, "--input-text"));
+    let interval =
+        Duration::from_millis(or_exit(take_number(&mut args, "--interval")).unwrap_or(500) as u64);
+    // Selections cover all years unless one is given, single days default to the latest year
+    let selected_year = or_exit(take_number(&mut args, "--year")).map(|year| year as u16);
+    let year = selected_year.unwrap_or_else(|| registry().latest_year().unwrap_or(2024));
+    let mut answers = or_exit(AnswerStore::load(ANSWERS_FILE));
     // run all days
     if args.len() < 2 {
-        let directory_path = "data";
-        let mut data_files = vec![];
-
-        // Read the directory contents
-        if let Ok(entries) = fs::read_dir(directory_path) {
-            for entry in entries {
-                if let Ok(entry) = entry {
-                    // Get the file name
-                    let file_name = entry.file_name();
-                    data_files.push(file_name);
+        let selection = DaySelection::default().in_year(selected_year);
+        if !runner::run_all(&selection, options, &mut answers) {
+            process::exit(1);
+        }
+    // run the selected days, e.g. `all 1..5`
+    } else if args[1] == "all" {
+        let selection = or_exit(DaySelection::parse(&args[2..])).in_year(selected_year);
+        if !runner::run_all(&selection, options, &mut answers) {
+            process::exit(1);
+        }
+    // generate a new day from the template
+    } else if args[1] == "new" {
+        let Some(day) = args.get(2) else {
+            exit_with("Please specify the new day, e.g. `new day12`!");
+        };
+        match scaffold::new_day(year, day, Path::new("src"), Path::new("examples")) {
+            Ok(created) => {
+                for path in created {
+                    println!("Created {}", path.display());
                 }
             }
-        } else {
-            println!("Failed to read directory");
+            Err(error) => exit_with(error),
         }
-
-        for file in data_files {
-            let file_name = file.to_str().unwrap();
-            let day = file_name.split(".").collect::<Vec<&str>>()[0];
-            let input = format!("{}/{}", directory_path, file_name);
-            run(&day.to_string(), &input);
+    // list all registered days
+    } else if args[1] == "list" {
+        runner::list();
+    // benchmark all variants of the given days
+    } else if args[1] == "bench" {
+        if !runner::bench_days(
+            &or_exit(DaySelection::parse(&args[2..])).in_year(selected_year),
+            &bench_config,
+            &baseline_options,
+        ) {
+            process::exit(1);
         }
-    // run a specific day
-    } else if args.len() == 2 {
-        let day = args.get(1).expect("Please specify day as first argument!");
-        let input = format!("data/{}.txt", day);
-
-        run(&day, &input);
-    } else if args.len() >= 3 {
-        let day = args.get(1).expect("Please specify day as first argument!");
-        let input = args[2..].join(" ");
+    // re-run a day whenever its input or examples change, e.g. `watch day06 [input]`
+    } else if args[1] == "watch" {
+        let Some(day) = args.get(2) else {
+            exit_with("Please specify the day to watch, e.g. `watch day06`!");
+        };
+        let day = day_arg(day);
+        let input = args
+            .get(3)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(format!("data/{}/day{:02}.txt", year, day)));
+        if !watch::watch(year, day, &input, options, &answers, interval) {
+            process::exit(1);
+        }
+    // submit the answer of one part, e.g. `submit day06 --part 2 [input]`
+    } else if args[1] == "submit" {
+        let Some(day) = args.get(2) else {
+            exit_with("Please specify the day to submit, e.g. `submit day06 --part 1`!");
+        };
+        let day = day_arg(day);
+        let part = match options.parts {
+            Parts::Part1 => 1,
+            Parts::Part2 => 2,
+            _ => exit_with("Please specify the part to submit with --part 1 or --part 2!"),
+        };
+        let input = args.get(3).map(|arg| InputSource::from_arg(arg));
+        if !runner::submit_day(year, day, input, part, &mut answers) {
+            process::exit(1);
+        }
+    // run a specific day on `data/YYYY/dayNN.txt`, the given file, stdin (`-`) or `--input-text`
+    } else if args.len() <= 3 {
+        let day = day_arg(&args[1]);
+        let input = match (input_text, args.get(2)) {
+            (Some(text), None) => InputSource::Text(text),
+            (None, Some(arg)) => InputSource::from_arg(arg),
+            (None, None) => InputSource::for_day(year, day),
+            (Some(_), Some(_)) => exit_with("Give either an input file or --input-text, not both!"),
+        };
 
-        run(&day, &input);
+        if !runner::run_single(year, day, &input, options, &mut answers) {
+            process::exit(1);
+        }
+    } else {
+        exit_with("Too many arguments, quote the input file if its path contains spaces!");
     }
 }
+
+// The day of a command line argument like `day03` or `3`, exits if it is not a valid day
+fn day_arg(arg: &str) -> u8 {
+    or_exit(parse_day(arg))
+}
@@ -0,0 +1,80 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Maps `items` with `function` on `jobs` worker threads and passes the results to `output`
+/// in the order of the items, each as soon as all results before it are done.
+///
+/// The workers are plain threads and not part of a rayon pool, so solvers which use rayon
+/// internally share the global pool instead of being confined to their worker.
+pub fn ordered_map<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    function: impl Fn(&T) -> R + Sync,
+    mut output: impl FnMut(R),
+) {
+    if jobs <= 1 {
+        items.iter().map(function).for_each(output);
+        return;
+    }
+
+    let next_item = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next_item, function) = (&next_item, &function);
+            scope.spawn(move || loop {
+                let index = next_item.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                if sender.send((index, function(item))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // Results which are done but still wait for an earlier one
+        let mut pending = BTreeMap::new();
+        let mut next_output = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&next_output) {
+                output(result);
+                next_output += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_ordered_map() {
+        let items = (0..20).collect::<Vec<u64>>();
+        for jobs in [1, 4, 50] {
+            let mut results = vec![];
+            ordered_map(
+                &items,
+                jobs,
+                |&i| {
+                    // Early items finish last
+                    thread::sleep(Duration::from_millis(20 - i));
+                    i * i
+                },
+                |r| results.push(r),
+            );
+            assert_eq!(items.iter().map(|i| i * i).collect::<Vec<_>>(), results);
+        }
+    }
+}
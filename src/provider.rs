@@ -0,0 +1,428 @@
+use std::{
+    env, fs,
+    path::PathBuf,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use once_cell::sync::Lazy;
+
+/// Where the personal puzzle inputs are cached, one directory per year
+pub const DATA_DIR: &str = "data";
+/// The session cookie of the logged in user, needed to download the personal inputs
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the server, e.g. to run against a local stand-in
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Sent with every request, so the Advent of Code maintainers know where the traffic comes from
+pub const USER_AGENT: &str = concat!(
+    "github.com/phillies/advent2024 v",
+    env!("CARGO_PKG_VERSION")
+);
+/// Minimum time between two requests to the server
+pub const DEFAULT_RATE_LIMIT: Duration = Duration::from_secs(3);
+
+/// Status code and body of an HTTP response, error statuses included
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The HTTP backend of the provider, replaced by a mock in the tests. Errors are transport
+/// errors, e.g. an unreachable server.
+pub trait HttpClient: Send + Sync {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, String>;
+    /// Sends the form URL-encoded
+    fn post_form(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> Result<HttpResponse, String>;
+}
+
+/// The real HTTP backend
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, String> {
+        let mut request = self.agent.get(url);
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+        into_response(request.call())
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        form: &[(&str, &str)],
+    ) -> Result<HttpResponse, String> {
+        let mut request = self.agent.post(url);
+        for (name, value) in headers {
+            request = request.set(name, value);
+        }
+        into_response(request.send_form(form))
+    }
+}
+
+// ureq reports error statuses as errors, but they are regular responses for us
+fn into_response(result: Result<ureq::Response, ureq::Error>) -> Result<HttpResponse, String> {
+    let response = match result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(error) => return Err(error.to_string()),
+    };
+    let status = response.status();
+    let body = response.into_string().map_err(|e| e.to_string())?;
+    Ok(HttpResponse { status, body })
+}
+
+/// Keeps a minimum interval between requests, also if they are sent from several threads
+pub struct RateLimiter {
+    interval: Duration,
+    last: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// Blocks until the next request may be sent
+    pub fn wait(&self) {
+        // The lock is held while sleeping, so waiting threads are let through one by one
+        let mut last = self.last.lock().expect("Rate limiter poisoned!");
+        if let Some(previous) = *last {
+            thread::sleep(self.interval.saturating_sub(previous.elapsed()));
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+/// Provides the personal puzzle inputs: from the cache if they were loaded before, otherwise
+/// they are downloaded and stored in the cache. Without a session only the cache is used.
+/// All requests to the server go through the provider, so they share the rate limit.
+pub struct InputProvider {
+    cache_dir: PathBuf,
+    base_url: String,
+    session: Option<String>,
+    client: Box<dyn HttpClient>,
+    limiter: RateLimiter,
+}
+
+impl InputProvider {
+    pub fn new(
+        cache_dir: impl Into<PathBuf>,
+        base_url: &str,
+        session: Option<String>,
+        client: Box<dyn HttpClient>,
+    ) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            client,
+            limiter: RateLimiter::new(DEFAULT_RATE_LIMIT),
+        }
+    }
+
+    /// Takes the session and the server from `AOC_SESSION` and `AOC_BASE_URL`
+    pub fn from_env(cache_dir: impl Into<PathBuf>) -> Self {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or(DEFAULT_BASE_URL.to_string());
+        let session = env::var(SESSION_VAR).ok().filter(|s| !s.trim().is_empty());
+        Self::new(
+            cache_dir,
+            &base_url,
+            session,
+            Box::new(UreqClient::default()),
+        )
+    }
+
+    pub fn with_rate_limit(mut self, interval: Duration) -> Self {
+        self.limiter = RateLimiter::new(interval);
+        self
+    }
+
+    /// The cached input of a day, e.g. `data/2024/day06.txt`
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let path = self.cache_path(year, day);
+        if let Ok(input) = fs::read_to_string(&path) {
+            return Ok(input);
+        }
+        if self.session.is_none() {
+            return Err(format!(
+                "{} not available, set {} to download it",
+                path.display(),
+                SESSION_VAR
+            ));
+        }
+        let input = self.download(year, day)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Could not create {}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, &input)
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+        Ok(input)
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let cookie = self.cookie()?;
+        self.limiter.wait();
+        let response = self
+            .client
+            .get(&url, &[("Cookie", &cookie)])
+            .map_err(|e| format!("Could not download {}: {}", url, e))?;
+        match response.status {
+            200 => Ok(response.body),
+            404 => Err(format!("Input of {} day {} is not unlocked yet", year, day)),
+            // The server answers with 400 or 500 if the session is unknown or expired
+            400 | 500 => Err(format!(
+                "Could not download {}: the session in {} was rejected",
+                url, SESSION_VAR
+            )),
+            status => Err(format!("Could not download {}: status {}", url, status)),
+        }
+    }
+
+    /// Posts a form to a path of the server, e.g. `/2024/day/1/answer`
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<HttpResponse, String> {
+        let url = format!("{}{}", self.base_url, path);
+        let cookie = self.cookie()?;
+        self.limiter.wait();
+        self.client
+            .post_form(&url, &[("Cookie", &cookie)], form)
+            .map_err(|e| format!("Could not send to {}: {}", url, e))
+    }
+
+    fn cookie(&self) -> Result<String, String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => Err(format!("{} is not set", SESSION_VAR)),
+        }
+    }
+}
+
+static PROVIDER: Lazy<InputProvider> = Lazy::new(|| InputProvider::from_env(DATA_DIR));
+
+/// The provider of the inputs in `data/`, configured from the environment
+pub fn default_provider() -> &'static InputProvider {
+    &PROVIDER
+}
+
+/// Stand-ins for the server in the tests
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread::{self, JoinHandle},
+    };
+
+    use super::{HttpClient, HttpResponse};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Request {
+        pub url: String,
+        pub headers: Vec<(String, String)>,
+        pub form: Vec<(String, String)>,
+    }
+
+    /// Answers every request with the same response and remembers the requests, clones share
+    /// the requests
+    #[derive(Clone)]
+    pub struct MockClient {
+        response: HttpResponse,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockClient {
+        pub fn new(status: u16, body: &str) -> Self {
+            Self {
+                response: HttpResponse {
+                    status,
+                    body: body.to_string(),
+                },
+                requests: Arc::default(),
+            }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        fn respond(
+            &self,
+            url: &str,
+            headers: &[(&str, &str)],
+            form: &[(&str, &str)],
+        ) -> HttpResponse {
+            let owned = |pairs: &[(&str, &str)]| {
+                pairs
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect()
+            };
+            self.requests.lock().unwrap().push(Request {
+                url: url.to_string(),
+                headers: owned(headers),
+                form: owned(form),
+            });
+            self.response.clone()
+        }
+    }
+
+    impl HttpClient for MockClient {
+        fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse, String> {
+            Ok(self.respond(url, headers, &[]))
+        }
+
+        fn post_form(
+            &self,
+            url: &str,
+            headers: &[(&str, &str)],
+            form: &[(&str, &str)],
+        ) -> Result<HttpResponse, String> {
+            Ok(self.respond(url, headers, form))
+        }
+    }
+
+    /// A local HTTP server which answers a single request with the given status line and body.
+    /// Returns its base URL and the thread, which hands back the whole request in lower case.
+    pub fn serve_once(status: &str, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut buffer = [0; 4096];
+            while !is_complete(&request) {
+                let length = stream.read(&mut buffer).unwrap();
+                if length == 0 {
+                    break;
+                }
+                request += &String::from_utf8_lossy(&buffer[..length]).to_lowercase();
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (base_url, server)
+    }
+
+    // Whether the headers and the whole body of a (lower case) request were received
+    fn is_complete(request: &str) -> bool {
+        let Some((headers, body)) = request.split_once("\r\n\r\n") else {
+            return false;
+        };
+        let length = headers
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length: "))
+            .and_then(|length| length.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+        body.len() >= length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mock::*, *};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("advent2024-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_cache_first() {
+        let cache_dir = temp_dir("provider");
+        let client = MockClient::new(200, "3   4\n");
+        let provider = InputProvider::new(
+            &cache_dir,
+            "http://localhost/",
+            Some("abc".to_string()),
+            Box::new(client.clone()),
+        )
+        .with_rate_limit(Duration::ZERO);
+
+        assert_eq!(Ok("3   4\n".to_string()), provider.input(2024, 1));
+        assert_eq!(Ok("3   4\n".to_string()), provider.input(2024, 1));
+        let requests = client.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!("http://localhost/2024/day/1/input", requests[0].url);
+        assert_eq!(
+            vec![("Cookie".to_string(), "session=abc".to_string())],
+            requests[0].headers
+        );
+        assert!(cache_dir.join("2024/day01.txt").exists());
+
+        // Without a session only the cache is used
+        let offline = InputProvider::new(&cache_dir, "", None, Box::new(UreqClient::default()));
+        assert!(offline.input(2024, 1).is_ok());
+        assert!(offline.input(2024, 2).unwrap_err().contains(SESSION_VAR));
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_stand_in_server() {
+        let (base_url, server) = serve_once("404 Not Found", "");
+        let cache_dir = temp_dir("stand-in");
+        let provider = InputProvider::new(
+            &cache_dir,
+            &base_url,
+            Some("abc".to_string()),
+            Box::new(UreqClient::default()),
+        );
+        assert_eq!(
+            Err("Input of 2024 day 25 is not unlocked yet".to_string()),
+            provider.input(2024, 25)
+        );
+        let request = server.join().unwrap();
+        assert!(request.starts_with("get /2024/day/25/input "));
+        assert!(request.contains(&format!("user-agent: {}", USER_AGENT.to_lowercase())));
+        assert!(request.contains("cookie: session=abc"));
+        assert!(!cache_dir.exists());
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let limiter = RateLimiter::new(Duration::from_millis(50));
+        let start = Instant::now();
+        limiter.wait();
+        assert!(start.elapsed() < Duration::from_millis(50));
+        limiter.wait();
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+}
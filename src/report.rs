@@ -0,0 +1,106 @@
+use serde::Serialize;
+
+/// How the runner reports its results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    // Human readable lines and the timing table
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        match text {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "Unknown format {}, expected text, json or csv",
+                text
+            )),
+        }
+    }
+}
+
+/// Result of one part of one day for machine-readable output
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartRecord {
+    pub day: String,
+    pub variant: String,
+    pub part: u8,
+    pub answer: String,
+    // `correct`, `incorrect`, `rejected` or `unknown`
+    pub verification: String,
+    pub expected: Option<String>,
+    // Median over all repetitions
+    pub elapsed_ns: u64,
+    pub input: String,
+}
+
+const CSV_HEADER: &str = "day,variant,part,answer,verification,expected,elapsed_ns,input";
+
+pub fn to_json(records: &[PartRecord]) -> String {
+    serde_json::to_string_pretty(records).expect("Records are always serializable")
+}
+
+pub fn to_csv(records: &[PartRecord]) -> String {
+    let mut content = CSV_HEADER.to_string() + "\n";
+    for record in records {
+        let fields = [
+            record.day.clone(),
+            record.variant.clone(),
+            record.part.to_string(),
+            record.answer.clone(),
+            record.verification.clone(),
+            record.expected.clone().unwrap_or_default(),
+            record.elapsed_ns.to_string(),
+            record.input.clone(),
+        ];
+        content += &fields.map(|f| csv_field(&f)).join(",");
+        content += "\n";
+    }
+    content
+}
+
+// Answers like `4,6,3,5` or multi-line answers have to be quoted
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formats() {
+        let records = [PartRecord {
+            day: "day17".to_string(),
+            variant: "default".to_string(),
+            part: 1,
+            answer: "4,6,3,5".to_string(),
+            verification: "incorrect".to_string(),
+            expected: Some("4,6,3,6".to_string()),
+            elapsed_ns: 1500,
+            input: "data/day17.txt".to_string(),
+        }];
+
+        assert_eq!(
+            format!(
+                "{}\nday17,default,1,\"4,6,3,5\",incorrect,\"4,6,3,6\",1500,data/day17.txt\n",
+                CSV_HEADER
+            ),
+            to_csv(&records)
+        );
+
+        let json = serde_json::from_str::<serde_json::Value>(&to_json(&records)).unwrap();
+        assert_eq!("4,6,3,5", json[0]["answer"]);
+        assert_eq!(1500, json[0]["elapsed_ns"]);
+        assert_eq!(Format::Json, Format::parse("json").unwrap());
+        assert!(Format::parse("xml").is_err());
+    }
+}
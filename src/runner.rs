@@ -0,0 +1,484 @@
+use std::{path::PathBuf, time::Instant};
+
+use crate::{
+    answer::Answer,
+    answers::{AnswerStore, Verification},
+    baseline::{self, BenchRecord},
+    bench::{self, BenchConfig},
+    error::SolveError,
+    input::InputSource,
+    parallel,
+    provider::default_provider,
+    registry,
+    report::{self, Format, PartRecord},
+    selection::DaySelection,
+    solver::DynSolver,
+    submit::{self, Submission},
+    timing::{format_duration, measure, print_summary, Timing},
+};
+
+/// Where the confirmed answers are stored
+pub const ANSWERS_FILE: &str = "data/answers.toml";
+
+/// Which phases of a day should be executed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    Both,
+    Part1,
+    Part2,
+    ParseOnly,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub parts: Parts,
+    // How often each phase is run to get min/median/max timings
+    pub repeat: usize,
+    // Store answers which are not in the answers file yet
+    pub record: bool,
+    // How many days are run concurrently
+    pub jobs: usize,
+    pub format: Format,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            parts: Parts::Both,
+            repeat: 1,
+            record: false,
+            jobs: 1,
+            format: Format::Text,
+        }
+    }
+}
+
+/// Where benchmark results are stored and how much slower a phase may get
+pub struct BaselineOptions {
+    pub baseline: PathBuf,
+    pub history: PathBuf,
+    pub save: bool,
+    // Relative slowdown of the mean that is tolerated, e.g. 0.05 for 5%
+    pub threshold: f64,
+}
+
+/// Answer, timing and verification status of one part
+pub struct PartRun {
+    pub answer: Answer,
+    pub timing: Timing,
+    pub verification: Verification,
+}
+
+/// Answers and timings of all executed phases of one day
+pub struct DayRun {
+    // The id of the day, e.g. `2024/day03`
+    pub day: String,
+    pub variant: &'static str,
+    pub input: String,
+    pub parse: Timing,
+    pub part_1: Option<PartRun>,
+    pub part_2: Option<PartRun>,
+}
+
+impl DayRun {
+    pub fn parts(&self) -> impl Iterator<Item = (u8, &PartRun)> {
+        [(1, &self.part_1), (2, &self.part_2)]
+            .into_iter()
+            .filter_map(|(part, run)| Some((part, run.as_ref()?)))
+    }
+
+    fn summary_rows(&self) -> Vec<(String, &'static str, &Timing)> {
+        let day = self.day.to_uppercase();
+        let mut rows = vec![(day.clone(), "parse", &self.parse)];
+        for (part, run) in self.parts() {
+            rows.push((
+                day.clone(),
+                ["part 1", "part 2"][part as usize - 1],
+                &run.timing,
+            ));
+        }
+        rows
+    }
+
+    pub fn records(&self) -> Vec<PartRecord> {
+        self.parts()
+            .map(|(part, run)| PartRecord {
+                day: self.day.clone(),
+                variant: self.variant.to_string(),
+                part,
+                answer: run.answer.to_string(),
+                verification: run.verification.status().to_string(),
+                expected: match &run.verification {
+                    Verification::Incorrect { expected } => Some(expected.clone()),
+                    _ => None,
+                },
+                elapsed_ns: run.timing.median().as_nanos() as u64,
+                input: self.input.clone(),
+            })
+            .collect()
+    }
+}
+
+// Multi-line answers like rendered letters start on a new line so they stay readable
+fn format_answer(answer: &Answer) -> String {
+    if answer.is_multi_line() {
+        format!("\n{}\n", answer)
+    } else {
+        answer.to_string()
+    }
+}
+
+/// Parses the input and runs the requested parts of a solver
+pub fn run_solver(
+    solver: &'static dyn DynSolver,
+    input: &str,
+    input_data: &str,
+    options: Options,
+    answers: &AnswerStore,
+) -> Result<DayRun, SolveError> {
+    let day = solver.id();
+
+    // The parsed input is shared between both parts
+    let (parsed, parse) = measure(options.repeat, || solver.parse(input_data));
+    let parsed = parsed?;
+    let mut run = DayRun {
+        day: day.clone(),
+        variant: solver.variant(),
+        input: input.to_string(),
+        parse,
+        part_1: None,
+        part_2: None,
+    };
+
+    let run_part = |part: u8| {
+        let (answer, timing) = measure(options.repeat, || match part {
+            1 => solver.part1(&parsed),
+            _ => solver.part2(&parsed),
+        });
+        let answer = answer?;
+        let verification = answers.verify(&day, part, &answer);
+        Ok(PartRun {
+            answer,
+            timing,
+            verification,
+        })
+    };
+    if matches!(options.parts, Parts::Both | Parts::Part1) {
+        run.part_1 = Some(run_part(1)?);
+    }
+    if matches!(options.parts, Parts::Both | Parts::Part2) {
+        run.part_2 = Some(run_part(2)?);
+    }
+    Ok(run)
+}
+
+// Prints a status message, which goes to stderr if stdout is reserved for the machine-readable
+// results
+fn print_status(format: Format, message: &str) {
+    if format == Format::Text {
+        println!("{}", message);
+    } else {
+        eprintln!("{}", message);
+    }
+}
+
+pub(crate) fn print_run(run: &DayRun) {
+    let mut output = format!(
+        "{}: Parsed in {}",
+        run.day.to_uppercase(),
+        format_duration(run.parse.median())
+    );
+    for (part, part_run) in run.parts() {
+        output += &format!(
+            ", Part {}: {} [{}] ({})",
+            part,
+            format_answer(&part_run.answer),
+            part_run.verification,
+            format_duration(part_run.timing.median())
+        );
+    }
+    println!("{}", output);
+}
+
+fn run_day(
+    year: u16,
+    day: u8,
+    input: &InputSource,
+    options: Options,
+    answers: &AnswerStore,
+) -> Option<DayRun> {
+    let Some(solver) = registry().get(year, &format!("day{:02}", day)) else {
+        print_status(
+            options.format,
+            &format!("Day {} of {} not implemented yet", day, year),
+        );
+        return None;
+    };
+    let input_data = match input.read() {
+        Ok(input_data) => input_data,
+        Err(error) => {
+            print_status(options.format, &error);
+            return None;
+        }
+    };
+
+    match run_solver(solver, &input.to_string(), &input_data, options, answers) {
+        Ok(run) => {
+            if options.format == Format::Text {
+                print_run(&run);
+            }
+            Some(run)
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            None
+        }
+    }
+}
+
+// Stores the answers of all parts which are not in the answers file yet
+fn record_answers(runs: &[DayRun], options: Options, answers: &mut AnswerStore) {
+    let mut recorded = 0;
+    for run in runs {
+        for (part, part_run) in run.parts() {
+            if part_run.verification == Verification::Unknown {
+                answers.record(&run.day, part, &part_run.answer);
+                recorded += 1;
+            }
+        }
+    }
+    let message = match answers.save() {
+        Ok(()) => format!("Recorded {} new answer(s) in {}", recorded, ANSWERS_FILE),
+        Err(error) => format!("Could not write {}: {}", ANSWERS_FILE, error),
+    };
+    print_status(options.format, &message);
+}
+
+// Prints the timing table in text mode, or all results in the machine-readable format
+fn print_results(runs: &[DayRun], format: Format) {
+    let records = runs.iter().flat_map(|r| r.records()).collect::<Vec<_>>();
+    match format {
+        Format::Text => print_summary(
+            &runs
+                .iter()
+                .flat_map(|r| r.summary_rows())
+                .collect::<Vec<_>>(),
+        ),
+        Format::Json => println!("{}", report::to_json(&records)),
+        Format::Csv => print!("{}", report::to_csv(&records)),
+    }
+}
+
+/// Runs a single day, the timing table is only worth printing for repeated runs.
+/// Returns false if the day could not be run.
+pub fn run_single(
+    year: u16,
+    day: u8,
+    input: &InputSource,
+    options: Options,
+    answers: &mut AnswerStore,
+) -> bool {
+    let Some(run) = run_day(year, day, input, options, answers) else {
+        return false;
+    };
+    if options.repeat > 1 || options.format != Format::Text {
+        print_results(std::slice::from_ref(&run), options.format);
+    }
+    if options.record {
+        record_answers(&[run], options, answers);
+    }
+    true
+}
+
+/// Runs one part of a day and submits its answer, the outcome is stored in the answers file.
+/// Returns false unless the answer was correct.
+pub fn submit_day(
+    year: u16,
+    day: u8,
+    input: Option<InputSource>,
+    part: u8,
+    answers: &mut AnswerStore,
+) -> bool {
+    let input = input.unwrap_or(InputSource::for_day(year, day));
+    let options = Options {
+        parts: [Parts::Part1, Parts::Part2][part as usize - 1],
+        ..Options::default()
+    };
+    let Some(run) = run_day(year, day, &input, options, answers) else {
+        return false;
+    };
+    let Some((_, part_run)) = run.parts().next() else {
+        return false;
+    };
+    match submit::submit(
+        default_provider(),
+        answers,
+        year,
+        day,
+        part,
+        &part_run.answer,
+    ) {
+        Ok(submission) => {
+            println!("Submitted {}: {}", part_run.answer, submission);
+            if let Err(error) = answers.save() {
+                println!("Could not write {}: {}", ANSWERS_FILE, error);
+            }
+            submission == Submission::Correct
+        }
+        Err(error) => {
+            println!("{}", error);
+            false
+        }
+    }
+}
+
+/// Lists all registered solvers including their variants with their metadata
+pub fn list() {
+    for solver in registry().iter_variants() {
+        println!(
+            "{}: {} day {:>2} - {} [{}]",
+            solver.id(),
+            solver.year(),
+            solver.day(),
+            solver.name(),
+            solver.variant()
+        );
+    }
+}
+
+// Result of one day when running several days
+enum Outcome {
+    Done(Box<DayRun>),
+    // The input file is missing
+    Skipped(String),
+    Failed(SolveError),
+}
+
+/// Runs all selected days, `--jobs` of them at the same time. The output is still printed in
+/// year and day order, days without input file are reported and skipped. A day that fails is reported
+/// as well and does not stop the others, but makes the function return false.
+pub fn run_all(selection: &DaySelection, options: Options, answers: &mut AnswerStore) -> bool {
+    let solvers = registry()
+        .iter()
+        .filter(|s| selection.contains(s.year(), s.day()))
+        .collect::<Vec<_>>();
+
+    let mut runs = vec![];
+    let mut failed = 0;
+    let start = Instant::now();
+    parallel::ordered_map(
+        &solvers,
+        options.jobs,
+        |&solver| {
+            let input = InputSource::for_day(solver.year(), solver.day());
+            let Ok(input_data) = input.read() else {
+                return Outcome::Skipped(format!(
+                    "{}: {} not available, skipped",
+                    solver.id().to_uppercase(),
+                    input
+                ));
+            };
+            match run_solver(solver, &input.to_string(), &input_data, options, answers) {
+                Ok(run) => Outcome::Done(Box::new(run)),
+                Err(error) => Outcome::Failed(error),
+            }
+        },
+        |outcome| match outcome {
+            Outcome::Done(run) => {
+                if options.format == Format::Text {
+                    print_run(&run);
+                }
+                runs.push(*run);
+            }
+            Outcome::Skipped(message) => print_status(options.format, &message),
+            Outcome::Failed(error) => {
+                eprintln!("{}", error);
+                failed += 1;
+            }
+        },
+    );
+    print_results(&runs, options.format);
+    // The total above adds up the days, which overlap when they run concurrently
+    if options.jobs > 1 {
+        print_status(
+            options.format,
+            &format!("Wall time: {}", format_duration(start.elapsed())),
+        );
+    }
+    if options.record {
+        record_answers(&runs, options, answers);
+    }
+    if failed > 0 {
+        print_status(options.format, &format!("{} day(s) failed", failed));
+    }
+    failed == 0
+}
+
+/// Benchmarks every registered variant of the selected days.
+/// Returns false if any phase got significantly slower than the stored baseline.
+pub fn bench_days(
+    selection: &DaySelection,
+    config: &BenchConfig,
+    options: &BaselineOptions,
+) -> bool {
+    let mut results = vec![];
+    for solver in registry()
+        .iter_variants()
+        .filter(|s| selection.contains(s.year(), s.day()))
+    {
+        let input = InputSource::for_day(solver.year(), solver.day());
+        let Ok(input_data) = input.read() else {
+            println!("{}: {} not available, skipped", solver.id(), input);
+            continue;
+        };
+        println!("Benchmarking {} [{}]", solver.id(), solver.variant());
+        match bench::bench_solver(solver, &input_data, config) {
+            Ok(solver_results) => results.extend(solver_results),
+            Err(error) => println!("{}, skipped", error),
+        }
+    }
+    println!();
+    bench::print_results(&results);
+
+    let commit = baseline::current_commit();
+    let timestamp = baseline::current_timestamp();
+    let records = results
+        .iter()
+        .map(|r| BenchRecord::from_result(r, &commit, timestamp))
+        .collect::<Vec<_>>();
+    if let Err(error) = baseline::append_records(&options.history, &records) {
+        println!("Could not write {}: {}", options.history.display(), error);
+    }
+
+    let stored = baseline::read_records(&options.baseline);
+    let regressions = baseline::find_regressions(&stored, &records, options.threshold);
+    if !stored.is_empty() {
+        println!();
+        println!(
+            "Compared to baseline {}: {} regression(s)",
+            options.baseline.display(),
+            regressions.len()
+        );
+    }
+    for regression in &regressions {
+        println!(
+            "{} {} {}: {:.0}ns -> {:.0}ns ({:+.1}%, baseline from commit {})",
+            regression.current.day.to_uppercase(),
+            regression.current.variant,
+            regression.current.phase,
+            regression.baseline.mean,
+            regression.current.mean,
+            regression.change() * 100.0,
+            regression.baseline.commit
+        );
+    }
+
+    if options.save {
+        let merged = baseline::merge_records(&stored, &records);
+        match baseline::write_records(&options.baseline, &merged) {
+            Ok(()) => println!("Saved baseline to {}", options.baseline.display()),
+            Err(error) => println!("Could not write {}: {}", options.baseline.display(), error),
+        }
+    }
+    regressions.is_empty()
+}
@@ -0,0 +1,167 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::selection::parse_day;
+
+const TEMPLATE: &str = include_str!("template.rs");
+
+// The module of a new year, which declares the modules of its days
+const YEAR_MODULE: &str =
+    "//! The solutions of YYYY, one module per day. `cargo run new dayNN` adds new days here.\n";
+
+// Expected answers of the generated example, commented out until they are known
+const EXAMPLE_ANSWERS: &str = "[example]\n# part1 = \"\"\n# part2 = \"\"\n";
+
+/// Generates the solver module `yYYYY/dayNN.rs` of a new day from `template.rs` plus an empty
+/// example input and the answers file of the examples, and declares the module in `yYYYY.rs`
+/// (and the year in `lib.rs` for a new year). The build script registers the module with the
+/// runner. Returns the created files, nothing is created if the day already exists.
+pub fn new_day(
+    year: u16,
+    day: &str,
+    source_dir: &Path,
+    examples_dir: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let day = parse_day(day)?;
+    let key = format!("day{:02}", day);
+
+    let year_dir = source_dir.join(format!("y{}", year));
+    let module = year_dir.join(format!("{}.rs", key));
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+    let fixture_dir = examples_dir.join(year.to_string()).join(&key);
+    let example = fixture_dir.join("example.txt");
+    let answers = fixture_dir.join("answers.toml");
+    if let Some(existing) = [&example, &answers].into_iter().find(|path| path.exists()) {
+        return Err(format!("{} already exists", existing.display()));
+    }
+
+    for dir in [&year_dir, &fixture_dir] {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+    }
+    for (path, content) in [
+        (&module, render_template(year, day)),
+        (&example, String::new()),
+        (&answers, EXAMPLE_ANSWERS.to_string()),
+    ] {
+        write(path, &content)?;
+    }
+    let mut created = vec![module, example, answers];
+
+    let year_module = source_dir.join(format!("y{}.rs", year));
+    if year_module.exists() {
+        declare_module(&year_module, &key, "day")?;
+    } else {
+        let header = YEAR_MODULE.replace("YYYY", &year.to_string());
+        write(&year_module, &format!("{}\npub mod {};\n", header, key))?;
+        declare_module(&source_dir.join("lib.rs"), &format!("y{}", year), "y")?;
+        created.push(year_module);
+    }
+    Ok(created)
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+// Adds `pub mod name;` to the declarations in `file` which start with `prefix`, keeping them
+// sorted. Without such declarations it goes after the last `pub mod`.
+fn declare_module(file: &Path, name: &str, prefix: &str) -> Result<(), String> {
+    let content = fs::read_to_string(file)
+        .map_err(|e| format!("Could not read {}: {}", file.display(), e))?;
+    let declaration = format!("pub mod {};", name);
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let is_sibling = |line: &&str| {
+        line.strip_prefix("pub mod ")
+            .is_some_and(|module| module.starts_with(prefix))
+    };
+    let index = match lines
+        .iter()
+        .rposition(|line| is_sibling(line) && *line < declaration.as_str())
+    {
+        Some(index) => index + 1,
+        None => lines
+            .iter()
+            .position(is_sibling)
+            .or_else(|| {
+                let last = lines.iter().rposition(|line| line.starts_with("pub mod "));
+                last.map(|index| index + 1)
+            })
+            .unwrap_or(lines.len()),
+    };
+    lines.insert(index, &declaration);
+    write(file, &(lines.join("\n") + "\n"))
+}
+
+// The template with all placeholders replaced, without the explanation at the top
+fn render_template(year: u16, day: u8) -> String {
+    let code = &TEMPLATE[TEMPLATE.find("use ").unwrap_or(0)..];
+    code.replace("DayXX", &format!("Day{:02}", day))
+        .replace("dayxx", &format!("day{:02}", day))
+        .replace(
+            "fn year(&self) -> u16 {\n        2024\n",
+            &format!("fn year(&self) -> u16 {{\n        {}\n", year),
+        )
+        .replace(
+            "fn day(&self) -> u8 {\n        0\n",
+            &format!("fn day(&self) -> u8 {{\n        {}\n", day),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("advent2024-scaffold-{}", std::process::id()));
+        let (source_dir, examples_dir) = (root.join("src"), root.join("examples"));
+        fs::create_dir_all(&source_dir).unwrap();
+        fs::write(
+            source_dir.join("lib.rs"),
+            "pub mod answer;\npub mod y2024;\n",
+        )
+        .unwrap();
+
+        let created = new_day(2023, "day12", &source_dir, &examples_dir).unwrap();
+        assert_eq!(4, created.len());
+        let module = fs::read_to_string(source_dir.join("y2023/day12.rs")).unwrap();
+        assert!(module.starts_with("use crate::"));
+        assert!(module.contains("pub struct Day12;"));
+        assert!(module.contains("fn year(&self) -> u16 {\n        2023\n"));
+        assert!(module.contains("fn day(&self) -> u8 {\n        12\n"));
+        assert!(!module.contains("XX") && !module.contains("xx"));
+        assert!(examples_dir.join("2023/day12/example.txt").exists());
+
+        // The modules are declared in order
+        new_day(2023, "day03", &source_dir, &examples_dir).unwrap();
+        let year_module = fs::read_to_string(source_dir.join("y2023.rs")).unwrap();
+        assert!(year_module.ends_with("\n\npub mod day03;\npub mod day12;\n"));
+        assert_eq!(
+            "pub mod answer;\npub mod y2023;\npub mod y2024;\n",
+            fs::read_to_string(source_dir.join("lib.rs")).unwrap()
+        );
+
+        // Existing days are never overwritten
+        assert!(new_day(2023, "12", &source_dir, &examples_dir).is_err());
+        assert!(new_day(2023, "day26", &source_dir, &examples_dir).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_template_runs() {
+        // `template.rs` is compiled with the tests, the generated days start out the same way
+        let mut registry = crate::solver::Registry::default();
+        crate::template::register(&mut registry, 2024);
+        let solver = registry.get(2024, "day00").unwrap();
+        let input = solver.parse("example").unwrap();
+        assert_eq!("0", solver.part1(&input).unwrap().to_string());
+        assert_eq!("0", solver.part2(&input).unwrap().to_string());
+    }
+}
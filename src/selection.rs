@@ -0,0 +1,87 @@
+/// A set of days given on the command line, e.g. `day01..day05`, `3,7,11` or `1..3,day10`.
+/// Ranges are inclusive, an empty selection contains all days. Without a year (`--year`) the
+/// days of all years are selected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaySelection {
+    year: Option<u16>,
+    days: Vec<u8>,
+}
+
+impl DaySelection {
+    /// Parses all arguments and combines them into one selection
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut days = vec![];
+        for item in args.iter().flat_map(|a| a.split(',')) {
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+            if let Some((start, end)) = item.split_once("..") {
+                let start = parse_day(start)?;
+                let end = parse_day(end.trim_start_matches('='))?;
+                if start > end {
+                    return Err(format!("Invalid day range {}", item));
+                }
+                days.extend(start..=end);
+            } else {
+                days.push(parse_day(item)?);
+            }
+        }
+        days.sort();
+        days.dedup();
+        Ok(Self { year: None, days })
+    }
+
+    /// Restricts the selection to the days of one year
+    pub fn in_year(self, year: Option<u16>) -> Self {
+        Self { year, ..self }
+    }
+
+    pub fn is_all(&self) -> bool {
+        self.days.is_empty()
+    }
+
+    pub fn contains(&self, year: u16, day: u8) -> bool {
+        self.year.is_none_or(|selected| selected == year)
+            && (self.is_all() || self.days.contains(&day))
+    }
+}
+
+/// Accepts `7`, `07` and `day07`
+pub fn parse_day(text: &str) -> Result<u8, String> {
+    let text = text.trim();
+    text.strip_prefix("day")
+        .unwrap_or(text)
+        .parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("Invalid day {}", text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<DaySelection, String> {
+        DaySelection::parse(&args.iter().map(|s| s.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn test_day_selection() {
+        let range = parse(&["day01..day05"]).unwrap();
+        assert_eq!(vec![1, 2, 3, 4, 5], range.days);
+
+        let list = parse(&["3,7,11"]).unwrap();
+        assert!(list.contains(2023, 7) && list.contains(2024, 7) && !list.contains(2024, 8));
+        let in_year = list.in_year(Some(2024));
+        assert!(in_year.contains(2024, 7) && !in_year.contains(2023, 7));
+
+        let mixed = parse(&["10..=12,day03", "3"]).unwrap();
+        assert_eq!(vec![3, 10, 11, 12], mixed.days);
+
+        assert!(parse(&[]).unwrap().is_all());
+        assert!(parse(&["day05..day01"]).is_err());
+        assert!(parse(&["day26"]).is_err());
+        assert!(parse(&["answers"]).is_err());
+    }
+}
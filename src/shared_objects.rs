@@ -1,4 +1,9 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::error::SolveError;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Position {
@@ -34,24 +39,25 @@ impl std::ops::Neg for Position {
     }
 }
 
+/// The four directions on a grid, `Up` is towards the first row
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     Up,
+    Right,
     Down,
     Left,
-    Right,
 }
 
 impl Direction {
-    pub fn next(self) -> Direction {
-        match self {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-        }
-    }
-    pub fn offset(self) -> Position {
+    /// All directions clockwise, starting with `Up`
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub const fn offset(self) -> Position {
         match self {
             Direction::Up => Position { x: 0, y: -1 },
             Direction::Down => Position { x: 0, y: 1 },
@@ -59,6 +65,34 @@ impl Direction {
             Direction::Right => Position { x: 1, y: 0 },
         }
     }
+    /// The arrow pointing in the direction, as used for the guard in the puzzles
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+    pub fn turn_right(self) -> Direction {
+        self.rotate(1)
+    }
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-1)
+    }
+    pub fn opposite(self) -> Direction {
+        self.rotate(2)
+    }
+    /// Turns clockwise by the given number of quarter turns, counterclockwise if negative
+    pub fn rotate(self, quarter_turns: i32) -> Direction {
+        Direction::ALL[(self as i32 + quarter_turns).rem_euclid(4) as usize]
+    }
+    #[deprecated(note = "use `turn_right`")]
+    pub fn next(self) -> Direction {
+        self.turn_right()
+    }
+    /// Up, down, left and right in this order, unlike `Direction::ALL`
+    #[deprecated(note = "use `Direction::ALL`, which is ordered clockwise")]
     pub fn all() -> Vec<Direction> {
         vec![
             Direction::Up,
@@ -69,17 +103,106 @@ impl Direction {
     }
 }
 
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    /// Parses arrows `^>v<`, `UDLR` and compass points `NESW`
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            _ => Err(format!("`{}` is not a direction", c)),
+        }
+    }
+}
+
 impl std::ops::Add<Direction> for Position {
     type Output = Position;
 
     /// Adds a direction to the current position
     fn add(self, direction: Direction) -> Position {
-        let offset = direction.offset();
-        Position {
-            x: self.x + offset.x,
-            y: self.y + offset.y,
+        self + direction.offset()
+    }
+}
+
+/// The eight directions on a grid including the diagonals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions clockwise, starting with `Up`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub const fn offset(self) -> Position {
+        match self {
+            Direction8::Up => Position { x: 0, y: -1 },
+            Direction8::UpRight => Position { x: 1, y: -1 },
+            Direction8::Right => Position { x: 1, y: 0 },
+            Direction8::DownRight => Position { x: 1, y: 1 },
+            Direction8::Down => Position { x: 0, y: 1 },
+            Direction8::DownLeft => Position { x: -1, y: 1 },
+            Direction8::Left => Position { x: -1, y: 0 },
+            Direction8::UpLeft => Position { x: -1, y: -1 },
         }
     }
+    /// Turns clockwise by 90°, like `Direction::turn_right`, see `rotate45` for the diagonals
+    pub fn turn_right(self) -> Direction8 {
+        self.rotate(1)
+    }
+    /// Turns counterclockwise by 90°
+    pub fn turn_left(self) -> Direction8 {
+        self.rotate(-1)
+    }
+    pub fn opposite(self) -> Direction8 {
+        self.rotate(2)
+    }
+    /// Turns clockwise by the given number of quarter turns, counterclockwise if negative
+    pub fn rotate(self, quarter_turns: i32) -> Direction8 {
+        self.rotate45(2 * quarter_turns)
+    }
+    /// Turns clockwise by the given number of eighth turns (45°), counterclockwise if negative
+    pub fn rotate45(self, eighth_turns: i32) -> Direction8 {
+        Direction8::ALL[(self as i32 + eighth_turns).rem_euclid(8) as usize]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+impl std::ops::Add<Direction8> for Position {
+    type Output = Position;
+
+    fn add(self, direction: Direction8) -> Position {
+        self + direction.offset()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -108,24 +231,43 @@ impl SparseGrid {
         self.entries.insert(pos, tile);
     }
 
-    pub fn from_input_data(input_data: &Vec<Vec<char>>) -> Self {
-        let max_row = input_data.len() as i32;
-        let max_col = input_data[0].len() as i32;
-        let mut sparse_grid = SparseGrid::new(max_row, max_col);
-        for y in 0..max_row {
-            for x in 0..max_col {
-                let tile = input_data[y as usize][x as usize] as char;
-                if tile != '.' {
-                    sparse_grid.set(Position { x, y }, tile);
+    /// Parses a rectangular grid, every character except the `empty` ones becomes an entry. The
+    /// `markers`, e.g. the start of a guard, are left out of the grid and returned with their
+    /// positions in reading order.
+    pub fn parse(
+        input: &str,
+        empty: &[char],
+        markers: &[char],
+    ) -> Result<(Self, Vec<(Position, char)>), SolveError> {
+        let (width, lines) = grid_lines(input)?;
+        let mut sparse_grid = SparseGrid::new(lines.len() as i32, width as i32);
+        let mut found_markers = vec![];
+        for (y, line) in lines.iter().enumerate() {
+            for (x, tile) in line.chars().enumerate() {
+                let pos = Position {
+                    x: x as i32,
+                    y: y as i32,
+                };
+                if markers.contains(&tile) {
+                    found_markers.push((pos, tile));
+                } else if !empty.contains(&tile) {
+                    sparse_grid.set(pos, tile);
                 }
             }
         }
-        sparse_grid
+        Ok((sparse_grid, found_markers))
+    }
+
+    /// Renders the grid with its entries and `.` for free tiles, see `Rendering` for overlays
+    pub fn render(&self) -> Rendering<'_> {
+        Rendering::new(self.max_col as usize, self.max_row as usize, move |pos| {
+            self.entries.get(pos).copied().unwrap_or('.').to_string()
+        })
     }
 
     pub fn get_unique_entries(&self) -> Vec<char> {
         let mut unique_entries = vec![];
-        for (_, &tile) in &self.entries {
+        for &tile in self.entries.values() {
             if !unique_entries.contains(&tile) {
                 unique_entries.push(tile);
             }
@@ -142,46 +284,525 @@ impl SparseGrid {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Which cells count as neighbours of a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity<'a> {
+    /// Up, down, left and right
+    Four,
+    /// Including the diagonals
+    Eight,
+    /// Any offsets, e.g. the jumps of a knight
+    Custom(&'a [Position]),
+}
+
+const FOUR_OFFSETS: [Position; 4] = [
+    Direction::Up.offset(),
+    Direction::Right.offset(),
+    Direction::Down.offset(),
+    Direction::Left.offset(),
+];
+const EIGHT_OFFSETS: [Position; 8] = [
+    Direction8::Up.offset(),
+    Direction8::UpRight.offset(),
+    Direction8::Right.offset(),
+    Direction8::DownRight.offset(),
+    Direction8::Down.offset(),
+    Direction8::DownLeft.offset(),
+    Direction8::Left.offset(),
+    Direction8::UpLeft.offset(),
+];
+
+impl<'a> Connectivity<'a> {
+    /// The offsets from a cell to its neighbours
+    pub fn offsets(self) -> &'a [Position] {
+        match self {
+            Connectivity::Four => &FOUR_OFFSETS,
+            Connectivity::Eight => &EIGHT_OFFSETS,
+            Connectivity::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// A dense grid stored row by row in a single `Vec`, the cell `(x, y)` is at `y * width + x`.
+/// `get` and `set` ignore positions outside of the grid, indexing with a `Position` panics.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Grid<T> {
-    pub data: Vec<Vec<T>>,
-    pub width: usize,
-    pub height: usize,
+    data: Vec<T>,
+    width: usize,
+    height: usize,
 }
 
-impl<T: Copy> Grid<T> {
+impl<T: Clone> Grid<T> {
     pub fn new(width: usize, height: usize, default_value: T) -> Self {
-        let mut data = vec![];
-        for _ in 0..height {
-            let mut row = vec![];
-            for _ in 0..width {
-                row.push(default_value);
-            }
-            data.push(row);
-        }
         Self {
-            data,
+            data: vec![default_value; width * height],
             width,
             height,
         }
     }
+}
 
-    pub fn get(&self, pos: &Position) -> Option<&T> {
-        if pos.x < 0 || pos.x >= self.width as i32 || pos.y < 0 || pos.y >= self.height as i32 {
-            None
-        } else {
-            Some(&self.data[pos.y as usize][pos.x as usize])
+impl<T> Grid<T> {
+    /// Parses a rectangular grid with one cell per character, errors of `parse_cell` are reported
+    /// at the character they occurred at
+    pub fn parse<E: Into<String>>(
+        input: &str,
+        parse_cell: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self, SolveError> {
+        let (width, lines) = grid_lines(input)?;
+        let mut data = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            for (index, c) in line.char_indices() {
+                let cell = parse_cell(c).map_err(|e| SolveError::at(input, &line[index..], e))?;
+                data.push(cell);
+            }
         }
+        Ok(Self {
+            data,
+            width,
+            height: lines.len(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_inside(&self, pos: &Position) -> bool {
+        pos.x >= 0 && (pos.x as usize) < self.width && pos.y >= 0 && (pos.y as usize) < self.height
+    }
+
+    // Index of a position in `data`, if it is inside of the grid
+    fn index_of(&self, pos: &Position) -> Option<usize> {
+        self.is_inside(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    pub fn get(&self, pos: &Position) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.data[index])
+    }
+
+    pub fn get_mut(&mut self, pos: &Position) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.data[index])
+    }
+
+    /// The cell at a position without bounds check
+    ///
+    /// # Safety
+    ///
+    /// The position must be inside of the grid, see `is_inside`
+    pub unsafe fn get_unchecked(&self, pos: &Position) -> &T {
+        self.data
+            .get_unchecked(pos.y as usize * self.width + pos.x as usize)
+    }
+
+    /// The cell at a position without bounds check
+    ///
+    /// # Safety
+    ///
+    /// The position must be inside of the grid, see `is_inside`
+    pub unsafe fn get_unchecked_mut(&mut self, pos: &Position) -> &mut T {
+        self.data
+            .get_unchecked_mut(pos.y as usize * self.width + pos.x as usize)
     }
 
     pub fn set(&mut self, pos: &Position, value: T) {
-        if pos.x >= 0 && pos.x < self.width as i32 && pos.y >= 0 && pos.y < self.height as i32 {
-            self.data[pos.y as usize][pos.x as usize] = value;
+        if let Some(cell) = self.get_mut(pos) {
+            *cell = value;
         }
     }
+
     pub fn set_xy(&mut self, pos_x: usize, pos_y: usize, value: T) {
         if pos_x < self.width && pos_y < self.height {
-            self.data[pos_y][pos_x] = value;
+            self.data[pos_y * self.width + pos_x] = value;
+        }
+    }
+
+    /// The cells of a row, panics if the row is outside of the grid
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks_exact(self.width.max(1))
+    }
+
+    /// The cells of a column from top to bottom, empty if the column is outside of the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width { &self.data[x..] } else { &[] };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    /// All cells row by row
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// All cells row by row
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// All positions of the grid row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| {
+            (0..width).map(move |x| Position {
+                x: x as i32,
+                y: y as i32,
+            })
+        })
+    }
+
+    /// All cells row by row together with their positions
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    /// The neighbours of a position which are inside of the grid, in the order of the offsets
+    pub fn neighbors<'a>(
+        &'a self,
+        pos: &Position,
+        connectivity: Connectivity<'a>,
+    ) -> impl Iterator<Item = (Position, &'a T)> + 'a {
+        let pos = *pos;
+        connectivity.offsets().iter().filter_map(move |&offset| {
+            let neighbor = pos + offset;
+            self.get(&neighbor).map(|cell| (neighbor, cell))
+        })
+    }
+}
+
+impl<T: fmt::Display> Grid<T> {
+    /// Renders each cell with its `Display`, see `Rendering` for overlays
+    pub fn render(&self) -> Rendering<'_> {
+        Rendering::new(self.width, self.height, move |pos| self[*pos].to_string())
+    }
+}
+
+// The lines of a grid without empty lines at the end, empty input and rows of different lengths
+// are errors
+fn grid_lines(input: &str) -> Result<(usize, Vec<&str>), SolveError> {
+    let mut lines = input.lines().collect::<Vec<_>>();
+    // Pasted input often ends with an extra newline
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    let Some(first_line) = lines.first() else {
+        return Err(SolveError::at(input, input, "empty grid"));
+    };
+    let width = first_line.chars().count();
+    if let Some(line) = lines.iter().find(|line| line.chars().count() != width) {
+        return Err(SolveError::at(
+            input,
+            line,
+            format!("expected {} tiles like the first row", width),
+        ));
+    }
+    Ok((width, lines))
+}
+
+impl<T> std::ops::Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        match self.index_of(&pos) {
+            Some(index) => &self.data[index],
+            None => panic!(
+                "{:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> std::ops::IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        match self.index_of(&pos) {
+            Some(index) => &mut self.data[index],
+            None => panic!(
+                "{:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render().fmt(f)
+    }
+}
+
+impl fmt::Display for SparseGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render().fmt(f)
+    }
+}
+
+/// Terminal colors for the layers of a `Rendering`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    fn ansi_code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+/// Positions drawn with the same character on top of a grid, e.g. the visited tiles of a walk
+#[derive(Debug, Clone)]
+pub struct Layer {
+    positions: HashSet<Position>,
+    tile: char,
+    color: Option<Color>,
+}
+
+impl Layer {
+    pub fn new(positions: impl IntoIterator<Item = Position>, tile: char) -> Self {
+        Self {
+            positions: positions.into_iter().collect(),
+            tile,
+            color: None,
+        }
+    }
+
+    /// Draws the layer in a color with ANSI escape codes
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// A grid as text, one line per row. Layers are drawn on top of the cells in the order they
+/// were added, the cursor is drawn last as an arrow in its direction.
+pub struct Rendering<'a> {
+    width: usize,
+    height: usize,
+    cell: Box<dyn Fn(&Position) -> String + 'a>,
+    layers: Vec<Layer>,
+    cursor: Option<(Position, Direction, Option<Color>)>,
+}
+
+impl<'a> Rendering<'a> {
+    /// A rendering of `width` x `height` cells, `cell` gives the text of a cell without overlays
+    pub fn new(width: usize, height: usize, cell: impl Fn(&Position) -> String + 'a) -> Self {
+        Self {
+            width,
+            height,
+            cell: Box::new(cell),
+            layers: vec![],
+            cursor: None,
+        }
+    }
+
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    pub fn cursor(mut self, pos: Position, direction: Direction, color: Option<Color>) -> Self {
+        self.cursor = Some((pos, direction, color));
+        self
+    }
+
+    // The text of a cell with the topmost overlay at its position
+    fn tile(&self, pos: &Position) -> String {
+        let overlay = match self.cursor {
+            Some((cursor, direction, color)) if cursor == *pos => Some((direction.arrow(), color)),
+            _ => self
+                .layers
+                .iter()
+                .rev()
+                .find(|layer| layer.positions.contains(pos))
+                .map(|layer| (layer.tile, layer.color)),
+        };
+        match overlay {
+            None => (self.cell)(pos),
+            Some((tile, None)) => tile.to_string(),
+            Some((tile, Some(color))) => format!("\x1b[{}m{}\x1b[0m", color.ansi_code(), tile),
+        }
+    }
+}
+
+impl fmt::Display for Rendering<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.width {
+                let pos = Position {
+                    x: x as i32,
+                    y: y as i32,
+                };
+                write!(f, "{}", self.tile(&pos))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid.set(&Position { x: 2, y: 1 }, 5);
+        grid.set(&Position { x: 3, y: 1 }, 7);
+        grid.set_xy(0, 1, 4);
+        grid[Position { x: 1, y: 0 }] = 1;
+
+        assert_eq!(Some(&5), grid.get(&Position { x: 2, y: 1 }));
+        assert_eq!(None, grid.get(&Position { x: -1, y: 0 }));
+        assert_eq!(4, grid[Position { x: 0, y: 1 }]);
+        assert_eq!(&[4, 0, 5], grid.row(1));
+        assert_eq!(vec![&1, &0], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(0, grid.column(3).count());
+        assert_eq!(2, grid.rows().count());
+        assert_eq!(&[0, 1, 0, 4, 0, 5], grid.as_slice());
+        assert_eq!(5, unsafe { *grid.get_unchecked(&Position { x: 2, y: 1 }) });
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("12\n34", |c| c.to_digit(10).ok_or("not a digit")).unwrap();
+        assert_eq!(&[1, 2, 3, 4], grid.as_slice());
+        assert_eq!(2, grid.height());
+
+        let input = "12\n3x";
+        let error = Grid::parse(input, |c| c.to_digit(10).ok_or("not a digit")).unwrap_err();
+        assert_eq!(SolveError::at(input, &input[4..], "not a digit"), error);
+        let input = "..#\n.#";
+        let error = SparseGrid::parse(input, &['.'], &[]).unwrap_err();
+        assert_eq!(
+            SolveError::at(input, &input[4..], "expected 3 tiles like the first row"),
+            error
+        );
+
+        let (sparse_grid, markers) = SparseGrid::parse(".#^\na.^", &['.'], &['^']).unwrap();
+        assert_eq!(
+            vec![
+                (Position { x: 2, y: 0 }, '^'),
+                (Position { x: 2, y: 1 }, '^')
+            ],
+            markers
+        );
+        assert_eq!(2, sparse_grid.entries.len());
+        assert_eq!(
+            Some(&'a'),
+            sparse_grid.entries.get(&Position { x: 0, y: 1 })
+        );
+        assert!(sparse_grid.is_free_tile(&Position { x: 2, y: 0 }));
+        assert_eq!((2, 3), (sparse_grid.max_row, sparse_grid.max_col));
+
+        // Empty lines at the end are ignored
+        let (sparse_grid, _) = SparseGrid::parse("..#\n.#.\n\n\n", &['.'], &[]).unwrap();
+        assert_eq!(2, sparse_grid.max_row);
+        assert!(SparseGrid::parse("\n\n", &['.'], &[]).is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::parse("12\n34", |c| c.to_digit(10).ok_or("not a digit")).unwrap();
+        assert_eq!("12\n34", grid.to_string());
+
+        let (sparse_grid, _) = SparseGrid::parse("#..\n.#.", &['.'], &[]).unwrap();
+        assert_eq!("#..\n.#.", sparse_grid.to_string());
+        let visited = [Position { x: 1, y: 0 }, Position { x: 2, y: 0 }];
+        let rendering = sparse_grid
+            .render()
+            .layer(Layer::new(visited, 'X'))
+            .layer(Layer::new([Position { x: 2, y: 1 }], 'O').color(Color::Red))
+            .cursor(Position { x: 2, y: 0 }, Direction::Right, None);
+        assert_eq!("#X>\n.#\x1b[31mO\x1b[0m", rendering.to_string());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse("123\n456", |c| c.to_digit(10).ok_or("not a digit")).unwrap();
+        let corner = Position { x: 0, y: 0 };
+        let cells = |connectivity| {
+            grid.neighbors(&corner, connectivity)
+                .map(|(_, &cell)| cell)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![2, 4], cells(Connectivity::Four));
+        assert_eq!(vec![2, 5, 4], cells(Connectivity::Eight));
+        assert_eq!(
+            vec![6],
+            cells(Connectivity::Custom(&[Position { x: 2, y: 1 }]))
+        );
+
+        assert_eq!(6, grid.positions().count());
+        assert_eq!(Some(Position { x: 1, y: 1 }), grid.positions().nth(4));
+        assert_eq!(
+            Some((Position { x: 2, y: 0 }, &3)),
+            grid.enumerate().find(|(_, &cell)| cell == 3)
+        );
+        assert_eq!(21, grid.iter().sum::<u32>());
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Right, Direction::Up.turn_right());
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+        assert_eq!(Direction::Down, Direction::Up.opposite());
+        assert_eq!(Direction::Left, Direction::Down.rotate(5));
+        assert_eq!(Direction::Right, Direction::Up.rotate(-3));
+        #[allow(deprecated)]
+        {
+            assert_eq!(Direction::Right, Direction::Up.next());
+            assert_eq!(
+                vec![
+                    Direction::Up,
+                    Direction::Down,
+                    Direction::Left,
+                    Direction::Right
+                ],
+                Direction::all()
+            );
+        }
+        assert_eq!(Direction8::DownLeft, Direction8::UpRight.opposite());
+        assert_eq!(Direction8::UpLeft, Direction8::DownLeft.turn_right());
+        assert_eq!(Direction8::UpRight, Direction8::Up.rotate45(1));
+        assert_eq!(Direction8::Left, Direction8::UpRight.rotate45(-3));
+        assert_eq!(Direction8::Up, Direction8::from(Direction::Up));
+
+        assert_eq!(Ok(Direction::Left), Direction::try_from('<'));
+        assert_eq!(Ok(Direction::Down), Direction::try_from('D'));
+        assert_eq!(Ok(Direction::Right), Direction::try_from('E'));
+        assert!(Direction::try_from('x').is_err());
+        for direction in Direction::ALL {
+            assert_eq!(Ok(direction), Direction::try_from(direction.arrow()));
         }
+        assert_eq!(
+            Position { x: 2, y: 0 },
+            Position { x: 1, y: 1 } + Direction8::UpRight
+        );
     }
 }
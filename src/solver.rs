@@ -0,0 +1,187 @@
+use std::any::Any;
+
+use crate::{answer::Answer, error::SolveError};
+
+/// The common interface of all days. Each day implements `Solver` on a unit struct and
+/// registers it with `register_solvers!`, the build script then picks up every
+/// `src/yYYYY/dayNN.rs` so there is no need to touch the runner when adding a new day.
+pub trait Solver {
+    /// The parsed puzzle input that is shared by both parts
+    type Input;
+
+    /// Title of the puzzle
+    fn name(&self) -> &'static str;
+    /// Days of other years than 2024 live in `src/yYYYY/` and override this, the registry checks
+    /// that it matches the directory
+    fn year(&self) -> u16 {
+        2024
+    }
+    fn day(&self) -> u8;
+    /// Name of the implementation if a day registers alternative solvers, e.g. to benchmark them
+    fn variant(&self) -> &'static str {
+        "default"
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError>;
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+    fn part2(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// Convenience function which parses the input and runs both parts, used by the tests
+    fn solve(&self, input: &str) -> Result<(Answer, Answer), SolveError> {
+        let parsed = self.parse(input)?;
+        Ok((self.part1(&parsed)?, self.part2(&parsed)?))
+    }
+}
+
+/// The parsed input of a type erased solver, it can only be used with the solver that created it
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// Object safe version of `Solver` so solvers with different input types can live in the
+/// same registry. Implemented automatically for every `Solver`.
+pub trait DynSolver: Sync {
+    fn name(&self) -> &'static str;
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn variant(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<ParsedInput, SolveError>;
+    fn part1(&self, input: &ParsedInput) -> Result<Answer, SolveError>;
+    fn part2(&self, input: &ParsedInput) -> Result<Answer, SolveError>;
+
+    /// The key used on the command line, e.g. `day03`
+    fn key(&self) -> String {
+        format!("day{:02}", self.day())
+    }
+
+    /// The key that is unique across years, e.g. `2024/day03`. It is also the path of the
+    /// puzzle input below `data/` and of the examples below `examples/`.
+    fn id(&self) -> String {
+        format!("{}/{}", self.year(), self.key())
+    }
+}
+
+impl<S> DynSolver for S
+where
+    S: Solver + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn name(&self) -> &'static str {
+        Solver::name(self)
+    }
+    fn year(&self) -> u16 {
+        Solver::year(self)
+    }
+    fn day(&self) -> u8 {
+        Solver::day(self)
+    }
+    fn variant(&self) -> &'static str {
+        Solver::variant(self)
+    }
+    fn parse(&self, input: &str) -> Result<ParsedInput, SolveError> {
+        match Solver::parse(self, input) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(error) => Err(with_origin(self, error)),
+        }
+    }
+    fn part1(&self, input: &ParsedInput) -> Result<Answer, SolveError> {
+        Solver::part1(self, downcast::<S>(input)).map_err(|e| with_origin(self, e))
+    }
+    fn part2(&self, input: &ParsedInput) -> Result<Answer, SolveError> {
+        Solver::part2(self, downcast::<S>(input)).map_err(|e| with_origin(self, e))
+    }
+}
+
+// Fills in the year and day of the solver an error comes from
+fn with_origin<S: Solver>(solver: &S, error: SolveError) -> SolveError {
+    error
+        .with_year(Solver::year(solver))
+        .with_day(Solver::day(solver))
+}
+
+fn downcast<S: Solver>(input: &ParsedInput) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref::<S::Input>()
+        .expect("Parsed input belongs to a different solver!")
+}
+
+#[derive(Default)]
+pub struct Registry {
+    solvers: Vec<&'static dyn DynSolver>,
+}
+
+impl Registry {
+    /// Adds a solver of a day in `src/yYYYY/`. Panics if the solver reports another year, it would
+    /// silently become a variant of the day with the same number in that year otherwise.
+    pub fn add(&mut self, year: u16, solver: &'static dyn DynSolver) {
+        if solver.year() != year {
+            panic!(
+                "{} is in src/y{}/ but reports the year {}, override `Solver::year`",
+                solver.id(),
+                year,
+                solver.year()
+            );
+        }
+        self.solvers.push(solver);
+        self.solvers.sort_by_key(|s| (s.year(), s.day()));
+    }
+
+    /// The default solver of a day, which is the first one the day registered
+    pub fn get(&self, year: u16, key: &str) -> Option<&'static dyn DynSolver> {
+        self.solvers
+            .iter()
+            .find(|s| s.year() == year && s.key() == key)
+            .copied()
+    }
+
+    /// All years with at least one solver, in ascending order
+    pub fn years(&self) -> Vec<u16> {
+        let mut years = self.solvers.iter().map(|s| s.year()).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+
+    /// The most recent year, which is used if no year is given on the command line
+    pub fn latest_year(&self) -> Option<u16> {
+        self.solvers.last().map(|s| s.year())
+    }
+
+    /// The default solver of every day ordered by year and day
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn DynSolver> + '_ {
+        self.solvers
+            .iter()
+            .enumerate()
+            .filter(|(index, s)| *index == 0 || self.solvers[index - 1].id() != s.id())
+            .map(|(_, s)| *s)
+    }
+
+    /// All registered solvers including the variants, ordered by year and day
+    pub fn iter_variants(&self) -> impl Iterator<Item = &'static dyn DynSolver> + '_ {
+        self.solvers.iter().copied()
+    }
+}
+
+/// Generates the `register` function of a day module, which is called by the
+/// build script generated code for every `src/yYYYY/dayNN.rs` with the year of its directory.
+/// The first solver is the default, all further ones are variants of it.
+#[macro_export]
+macro_rules! register_solvers {
+    ($($solver:expr),+ $(,)?) => {
+        pub fn register(registry: &mut $crate::solver::Registry, year: u16) {
+            $(registry.add(year, &$solver);)+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "2024/day02 is in src/y2025/ but reports the year 2024")]
+    fn test_register_checks_year() {
+        let mut registry = Registry::default();
+        crate::y2024::day02::register(&mut registry, 2025);
+    }
+}
@@ -0,0 +1,266 @@
+use std::{fmt, time::Duration};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    answer::Answer,
+    answers::{AnswerStore, Verification},
+    provider::InputProvider,
+};
+
+static ARTICLE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap());
+static TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]*>").unwrap());
+static WAIT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap());
+
+/// What the server said about a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong without a hint, e.g. for answers which are not numbers
+    Incorrect,
+    // The last answer was sent too recently, the server accepts the next one after the given time
+    Wait(Duration),
+    // The part is solved already or not unlocked yet
+    WrongLevel,
+    // A response we do not understand, with the text of its message
+    Unexpected(String),
+}
+
+impl Submission {
+    /// The server rejected the answer, so it must not be submitted again
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Submission::TooHigh | Submission::TooLow | Submission::Incorrect
+        )
+    }
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "correct"),
+            Submission::TooHigh => write!(f, "incorrect, too high"),
+            Submission::TooLow => write!(f, "incorrect, too low"),
+            Submission::Incorrect => write!(f, "incorrect"),
+            Submission::Wait(wait) => {
+                write!(f, "too soon, wait {}s before submitting", wait.as_secs())
+            }
+            Submission::WrongLevel => write!(f, "part already solved or not unlocked yet"),
+            Submission::Unexpected(message) => write!(f, "unexpected response: {}", message),
+        }
+    }
+}
+
+/// Interprets the HTML page the server sends back for a submitted answer
+pub fn parse_response(body: &str) -> Submission {
+    let message = message(body);
+    if message.contains("That's the right answer") {
+        Submission::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("too high") {
+            Submission::TooHigh
+        } else if message.contains("too low") {
+            Submission::TooLow
+        } else {
+            Submission::Incorrect
+        }
+    } else if message.contains("You gave an answer too recently") {
+        let wait = WAIT.captures(&message).map(|captures| {
+            let number = |index| {
+                captures
+                    .get(index)
+                    .map_or(0, |m| m.as_str().parse().unwrap_or(0))
+            };
+            Duration::from_secs(number(1) * 60 + number(2))
+        });
+        Submission::Wait(wait.unwrap_or(Duration::from_secs(60)))
+    } else if message.contains("You don't seem to be solving the right level") {
+        Submission::WrongLevel
+    } else {
+        Submission::Unexpected(message)
+    }
+}
+
+// The text of the `<article>` with the message, without markup and line breaks
+fn message(body: &str) -> String {
+    let article = ARTICLE
+        .captures(body)
+        .and_then(|captures| captures.get(1))
+        .map_or(body, |m| m.as_str());
+    TAG.replace_all(article, " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Submits the answer of a part and records the outcome in the answers store. Nothing is sent
+/// if the outcome is known already: confirmed answers and answers which were rejected before
+/// are refused with an explanation, as are multi-line answers which have to be read by a human.
+pub fn submit(
+    provider: &InputProvider,
+    answers: &mut AnswerStore,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+) -> Result<Submission, String> {
+    let key = format!("{}/day{:02}", year, day);
+    let not_submitted =
+        |reason: String| Err(format!("{} part {}: {}, not submitted", key, part, reason));
+    if answer.is_multi_line() {
+        return not_submitted("multi-line answers have to be read and submitted by hand".into());
+    }
+    match answers.verify(&key, part, answer) {
+        Verification::Correct => return not_submitted(format!("{} is confirmed already", answer)),
+        Verification::Incorrect { expected } => {
+            return not_submitted(format!(
+                "{} differs from the confirmed {}",
+                answer, expected
+            ))
+        }
+        Verification::Rejected => return not_submitted(format!("{} was rejected before", answer)),
+        Verification::Unknown => {}
+    }
+
+    let path = format!("/{}/day/{}/answer", year, day);
+    let form = [("level", part.to_string()), ("answer", answer.to_string())];
+    let form = form
+        .iter()
+        .map(|(name, value)| (*name, value.as_str()))
+        .collect::<Vec<_>>();
+    let response = provider.post(&path, &form)?;
+    if response.status != 200 {
+        return Err(format!(
+            "Could not submit {}: status {}",
+            key, response.status
+        ));
+    }
+
+    let submission = parse_response(&response.body);
+    if submission == Submission::Correct {
+        answers.record(&key, part, answer);
+    } else if submission.is_wrong() {
+        answers.reject(&key, part, answer);
+    }
+    Ok(submission)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::{
+        mock::{serve_once, MockClient},
+        UreqClient,
+    };
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><main>\n<article><p>{}</p></article>\n</main></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            Submission::Correct,
+            parse_response(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            ))
+        );
+        assert_eq!(
+            Submission::TooLow,
+            parse_response(&page("That's not the right answer; your answer is too low.  Please wait one minute before trying again."))
+        );
+        assert_eq!(
+            Submission::Incorrect,
+            parse_response(&page("That's not the right answer.  If you're stuck, ..."))
+        );
+        assert_eq!(
+            Submission::Wait(Duration::from_secs(76)),
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 16s left to wait."))
+        );
+        assert_eq!(
+            Submission::WrongLevel,
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+        );
+        assert_eq!(
+            Submission::Unexpected("Something else".to_string()),
+            parse_response(&page("Something\n<b>else</b>"))
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let mut answers = AnswerStore::load("does/not/exist.toml").unwrap();
+        let client = MockClient::new(
+            200,
+            &page("That's not the right answer; your answer is too high."),
+        );
+        let provider = InputProvider::new(
+            "",
+            "http://localhost",
+            Some("abc".to_string()),
+            Box::new(client.clone()),
+        )
+        .with_rate_limit(Duration::ZERO);
+
+        let answer = Answer::from(1234);
+        assert_eq!(
+            Ok(Submission::TooHigh),
+            submit(&provider, &mut answers, 2024, 1, 2, &answer)
+        );
+        assert_eq!(
+            Verification::Rejected,
+            answers.verify("2024/day01", 2, &answer)
+        );
+        let requests = client.requests();
+        assert_eq!("http://localhost/2024/day/1/answer", requests[0].url);
+        assert_eq!(
+            vec![
+                ("level".to_string(), "2".to_string()),
+                ("answer".to_string(), "1234".to_string())
+            ],
+            requests[0].form
+        );
+
+        // The rejected answer is not sent again
+        assert!(submit(&provider, &mut answers, 2024, 1, 2, &answer)
+            .unwrap_err()
+            .contains("rejected before"));
+        assert_eq!(1, client.requests().len());
+    }
+
+    #[test]
+    fn test_submit_to_stand_in_server() {
+        let (base_url, server) = serve_once("200 OK", &page("That's the right answer!"));
+        let provider = InputProvider::new(
+            "",
+            &base_url,
+            Some("abc".to_string()),
+            Box::new(UreqClient::default()),
+        );
+        let mut answers = AnswerStore::load("does/not/exist.toml").unwrap();
+
+        let answer = Answer::from(42);
+        assert_eq!(
+            Ok(Submission::Correct),
+            submit(&provider, &mut answers, 2024, 3, 1, &answer)
+        );
+        assert_eq!(Some("42"), answers.expected("2024/day03", 1));
+        let request = server.join().unwrap();
+        assert!(request.starts_with("post /2024/day/3/answer "));
+        assert!(request.ends_with("level=1&answer=42"));
+
+        // Confirmed answers are not sent again
+        assert!(submit(&provider, &mut answers, 2024, 3, 1, &answer).is_err());
+    }
+}
@@ -1,50 +1,39 @@
-/// That's my template for new days. Includes the solve function signature and a test function
-use crate::my_io::read_input_to_vector;
+// That's my template for new days. `cargo run new dayNN` generates `src/yYYYY/dayNN.rs` from it
+// and declares it in `src/yYYYY.rs`, the build script takes care of registering it. Everything
+// up to the first `use` is left out.
+// The examples are tested from `examples/YYYY/dayNN/`, so there is no need for a test function.
+use crate::{answer::Answer, error::SolveError, solver::Solver};
 
-pub fn solve(filename: &String) -> (i64, i64) {
-    let input = std::fs::read_to_string(filename).expect("Could not read file!");
-    // let input = read_input_to_vector(filename);
-    day_xx(&input)
-}
-
-// fn day_xx(input_data: &Vec<String>) -> (i64, i64) {
-fn day_xx(input_data: &String) -> (i64, i64) {
-    let mut result_1 = 0;
-    let mut result_2 = 0;
+pub struct DayXX;
 
-    (result_1, result_2)
-}
+impl Solver for DayXX {
+    // type Input = Vec<String>;
+    type Input = String;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::my_io::read_input_to_vector;
-    extern crate test;
-    use test::Bencher;
+    fn name(&self) -> &'static str {
+        "..."
+    }
+    fn year(&self) -> u16 {
+        2024
+    }
+    fn day(&self) -> u8 {
+        0
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        // input.lines().map(|s| s.to_string()).collect()
+        // input.split_whitespace().map(|s| crate::error::parse_at::<i64>(input, s)).collect()
+        Ok(input.to_string())
+    }
+    fn part1(&self, _input_data: &Self::Input) -> Result<Answer, SolveError> {
+        let result_1: i64 = 0;
 
-    #[test]
-    fn test_dayxx() {
-        let result_1 = 0;
-        let result_2 = 0;
-        let input = vec![
-            "...".to_string(),
-            "...".to_string(),
-            "...".to_string(),
-            "...".to_string(),
-        ]
-        .join("\n");
-        let (output_1, output_2) = day_xx(&input);
-        assert_eq!(result_1, output_1);
-        assert_eq!(result_2, output_2);
+        Ok(result_1.into())
     }
+    fn part2(&self, _input_data: &Self::Input) -> Result<Answer, SolveError> {
+        let result_2: i64 = 0;
 
-    #[bench]
-    fn bench_dayxx(b: &mut Bencher) {
-        let filename = "data/dayxx.txt";
-        let input = std::fs::read_to_string(filename).expect("Could not read file!");
-        // let input = read_input_to_vector(filename);
-        b.iter(|| {
-            day_xx(&input);
-        });
+        Ok(result_2.into())
     }
 }
+
+crate::register_solvers!(DayXX);
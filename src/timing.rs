@@ -0,0 +1,155 @@
+use std::time::{Duration, Instant};
+
+/// Wall times of repeated runs of one phase (parsing, part 1 or part 2)
+#[derive(Debug, Clone, Default)]
+pub struct Timing {
+    samples: Vec<Duration>,
+}
+
+impl Timing {
+    pub fn add(&mut self, sample: Duration) {
+        self.samples.push(sample);
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn mean(&self) -> Duration {
+        if self.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+
+    pub fn std_dev(&self) -> Duration {
+        if self.samples.len() < 2 {
+            return Duration::ZERO;
+        }
+        let mean = self.mean().as_secs_f64();
+        let variance = self
+            .samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / (self.samples.len() - 1) as f64;
+        Duration::from_secs_f64(variance.sqrt())
+    }
+
+    /// Removes samples outside of Tukey's fences (1.5 times the interquartile range below the
+    /// first or above the third quartile) and returns the remaining samples plus the number of
+    /// removed outliers
+    pub fn reject_outliers(&self) -> (Timing, usize) {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        if sorted.len() < 4 {
+            return (self.clone(), 0);
+        }
+        let first_quartile = sorted[sorted.len() / 4].as_secs_f64();
+        let third_quartile = sorted[sorted.len() * 3 / 4].as_secs_f64();
+        let fence = 1.5 * (third_quartile - first_quartile);
+        let samples = sorted
+            .into_iter()
+            .filter(|s| {
+                let s = s.as_secs_f64();
+                s >= first_quartile - fence && s <= third_quartile + fence
+            })
+            .collect::<Vec<_>>();
+        let outliers = self.samples.len() - samples.len();
+        (Timing { samples }, outliers)
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        match sorted.len() {
+            0 => Duration::ZERO,
+            n if n % 2 == 1 => sorted[n / 2],
+            n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        }
+    }
+}
+
+/// Runs `function` `repeat` times (at least once) and returns the result of the first run
+/// together with the timing of all runs
+pub fn measure<T>(repeat: usize, mut function: impl FnMut() -> T) -> (T, Timing) {
+    let mut timing = Timing::default();
+
+    let start = Instant::now();
+    let result = function();
+    timing.add(start.elapsed());
+
+    for _ in 1..repeat {
+        let start = Instant::now();
+        std::hint::black_box(function());
+        timing.add(start.elapsed());
+    }
+    (result, timing)
+}
+
+/// Short human readable duration, e.g. `1.23ms`
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+/// Prints one row per day and phase with min, median and max wall time and
+/// the total of the medians across all phases
+pub fn print_summary(rows: &[(String, &'static str, &Timing)]) {
+    println!();
+    println!(
+        "{:<12} {:<8} {:>12} {:>12} {:>12}",
+        "Day", "Phase", "Min", "Median", "Max"
+    );
+    let mut total = Duration::ZERO;
+    for (day, phase, timing) in rows {
+        println!(
+            "{:<12} {:<8} {:>12} {:>12} {:>12}",
+            day,
+            phase,
+            format_duration(timing.min()),
+            format_duration(timing.median()),
+            format_duration(timing.max())
+        );
+        total += timing.median();
+    }
+    println!("Total time: {}", format_duration(total));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_statistics() {
+        let mut timing = Timing::default();
+        for ms in [5, 1, 3, 2] {
+            timing.add(Duration::from_millis(ms));
+        }
+        assert_eq!(Duration::from_millis(1), timing.min());
+        assert_eq!(Duration::from_millis(5), timing.max());
+        assert_eq!(Duration::from_micros(2500), timing.median());
+
+        assert_eq!(Duration::from_micros(2750), timing.mean());
+
+        timing.add(Duration::from_millis(100));
+        let (cleaned, outliers) = timing.reject_outliers();
+        assert_eq!(1, outliers);
+        assert_eq!(Duration::from_millis(5), cleaned.max());
+
+        let (result, timing) = measure(3, || 42);
+        assert_eq!(42, result);
+        assert_eq!(3, timing.samples.len());
+    }
+}
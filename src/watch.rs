@@ -0,0 +1,257 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    answers::AnswerStore,
+    fixtures::{self, EXAMPLES_DIR},
+    registry,
+    runner::{self, DayRun, Options},
+    solver::DynSolver,
+    timing::format_duration,
+};
+
+/// Modification times of all watched files, `None` if a file does not exist (anymore)
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// The answers and median timings of one run, which are compared with the next run
+#[derive(Debug, Clone, Default, PartialEq)]
+struct RunSummary {
+    answers: [Option<String>; 2],
+    // Parse, part 1 and part 2
+    timings: [Option<Duration>; 3],
+}
+
+impl RunSummary {
+    fn from_run(run: &DayRun) -> Self {
+        let mut summary = RunSummary::default();
+        summary.timings[0] = Some(run.parse.median());
+        for (part, part_run) in run.parts() {
+            summary.answers[part as usize - 1] = Some(part_run.answer.to_string());
+            summary.timings[part as usize] = Some(part_run.timing.median());
+        }
+        summary
+    }
+}
+
+// Describes what changed between two runs, e.g. `Part 1: 11 -> 12` or `parse: 1.20ms -> 0.90ms`
+fn describe_changes(previous: &RunSummary, current: &RunSummary) -> Vec<String> {
+    let mut changes = vec![];
+    for (index, (before, after)) in previous.answers.iter().zip(&current.answers).enumerate() {
+        let part = index + 1;
+        match (before, after) {
+            (Some(before), Some(after)) if before != after => {
+                changes.push(format!("Part {}: {} -> {}", part, before, after))
+            }
+            (Some(before), Some(_)) => {
+                changes.push(format!("Part {}: {} (unchanged)", part, before))
+            }
+            _ => {}
+        }
+    }
+    let phases = ["parse", "part 1", "part 2"];
+    for (phase, (before, after)) in phases
+        .iter()
+        .zip(previous.timings.iter().zip(&current.timings))
+    {
+        if let (Some(before), Some(after)) = (before, after) {
+            let change = after.as_secs_f64() / before.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0;
+            changes.push(format!(
+                "{}: {} -> {} ({:+.1}%)",
+                phase,
+                format_duration(*before),
+                format_duration(*after),
+                change * 100.0
+            ));
+        }
+    }
+    changes
+}
+
+// The source of a day, e.g. `src/y2024/day06.rs`
+fn source_file(solver: &dyn DynSolver) -> PathBuf {
+    Path::new("src")
+        .join(format!("y{}", solver.year()))
+        .join(format!("{}.rs", solver.key()))
+}
+
+// The input, the source of the day and all of its examples
+fn take_snapshot(solver: &dyn DynSolver, input: &Path) -> Snapshot {
+    let mut paths = vec![input.to_path_buf(), source_file(solver)];
+    if let Ok(entries) = fs::read_dir(Path::new(EXAMPLES_DIR).join(solver.id())) {
+        paths.extend(entries.flatten().map(|entry| entry.path()));
+    }
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+fn changed_files(previous: &Snapshot, current: &Snapshot) -> Vec<PathBuf> {
+    let mut changed = current
+        .iter()
+        .filter(|(path, modified)| previous.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect::<Vec<_>>();
+    changed.extend(
+        previous
+            .keys()
+            .filter(|p| !current.contains_key(*p))
+            .cloned(),
+    );
+    changed
+}
+
+// Checks all examples of the day and prints the failures
+fn check_examples(id: &str) {
+    let Ok(fixtures) = fixtures::discover(Path::new(EXAMPLES_DIR)) else {
+        return;
+    };
+    let solvers = registry()
+        .iter_variants()
+        .filter(|s| s.id() == id)
+        .collect::<Vec<&dyn DynSolver>>();
+    let (mut checked, mut failures) = (0, vec![]);
+    for fixture in fixtures.iter().filter(|f| f.day == id) {
+        let Ok(input) = fixture.load() else {
+            continue;
+        };
+        for solver in &solvers {
+            failures.extend(fixture.check(*solver, &input));
+        }
+        checked += 1;
+    }
+    if failures.is_empty() {
+        println!("Examples: {} passed", checked);
+    } else {
+        println!("Examples: {} failure(s)", failures.len());
+        failures
+            .iter()
+            .for_each(|failure| println!("  {}", failure));
+    }
+}
+
+/// Polls the input, the source and the examples of a day and re-runs the day and its examples
+/// whenever one of them changes, showing how answers and timings changed since the last run.
+/// Changes of the source are only reported, as they need a rebuild to take effect. Runs until
+/// the process is stopped, returns false if the day does not exist.
+pub fn watch(
+    year: u16,
+    day: u8,
+    input: &Path,
+    options: Options,
+    answers: &AnswerStore,
+    interval: Duration,
+) -> bool {
+    let Some(solver) = registry().get(year, &format!("day{:02}", day)) else {
+        println!("Day {} of {} not implemented yet", day, year);
+        return false;
+    };
+    let source = source_file(solver);
+    let mut previous_run: Option<RunSummary> = None;
+    let mut snapshot = Snapshot::new();
+    println!(
+        "Watching {} and the examples of {}, stop with Ctrl-C",
+        input.display(),
+        solver.id()
+    );
+
+    loop {
+        let current = take_snapshot(solver, input);
+        let changed = changed_files(&snapshot, &current);
+        snapshot = current;
+        if changed.is_empty() {
+            thread::sleep(interval);
+            continue;
+        }
+        if changed.contains(&source) && previous_run.is_some() {
+            println!(
+                "{} changed, restart after rebuilding to run the new code",
+                source.display()
+            );
+        }
+
+        match fs::read_to_string(input) {
+            Ok(input_data) => {
+                let input_name = input.display().to_string();
+                match runner::run_solver(solver, &input_name, &input_data, options, answers) {
+                    Ok(run) => {
+                        runner::print_run(&run);
+                        let summary = RunSummary::from_run(&run);
+                        if let Some(previous) = &previous_run {
+                            for change in describe_changes(previous, &summary) {
+                                println!("  {}", change);
+                            }
+                        }
+                        previous_run = Some(summary);
+                    }
+                    Err(error) => println!("{}", error),
+                }
+            }
+            Err(_) => println!("{} not available", input.display()),
+        }
+        check_examples(&solver.id());
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_changes() {
+        let previous = RunSummary {
+            answers: [Some("11".to_string()), Some("31".to_string())],
+            timings: [
+                Some(Duration::from_millis(2)),
+                Some(Duration::from_millis(4)),
+                None,
+            ],
+        };
+        let current = RunSummary {
+            answers: [Some("12".to_string()), Some("31".to_string())],
+            timings: [
+                Some(Duration::from_millis(1)),
+                Some(Duration::from_millis(5)),
+                Some(Duration::from_millis(1)),
+            ],
+        };
+        assert_eq!(
+            vec![
+                "Part 1: 11 -> 12",
+                "Part 2: 31 (unchanged)",
+                "parse: 2.00ms -> 1.00ms (-50.0%)",
+                "part 1: 4.00ms -> 5.00ms (+25.0%)",
+            ],
+            describe_changes(&previous, &current)
+        );
+    }
+
+    #[test]
+    fn test_changed_files() {
+        let time = SystemTime::UNIX_EPOCH;
+        let previous = Snapshot::from([
+            (PathBuf::from("a"), Some(time)),
+            (PathBuf::from("b"), Some(time)),
+            (PathBuf::from("c"), None),
+        ]);
+        let current = Snapshot::from([
+            (PathBuf::from("a"), Some(time)),
+            (PathBuf::from("b"), Some(time + Duration::from_secs(1))),
+            (PathBuf::from("c"), Some(time)),
+        ]);
+        assert_eq!(
+            vec![PathBuf::from("b"), PathBuf::from("c")],
+            changed_files(&previous, &current)
+        );
+        assert!(changed_files(&current, &current).is_empty());
+    }
+}
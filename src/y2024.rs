@@ -0,0 +1,13 @@
+//! The solutions of 2024, one module per day. `cargo run new dayNN` adds new days here.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
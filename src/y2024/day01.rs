@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use crate::{
+    answer::Answer,
+    error::{parse_at, SolveError},
+    solver::Solver,
+};
+
+// The two ways of filling the lists, registered as variants so they can be benchmarked
+#[derive(Debug, Clone, Copy)]
+pub enum Parsing {
+    // Increases the vector size with each read line
+    Grow,
+    // Reads the input first and preallocates the vectors
+    Preallocate,
+}
+
+// Both lists plus how often each number occurs in the second list
+type Lists = (Vec<i64>, Vec<i64>, HashMap<i64, i64>);
+
+pub struct Day01 {
+    parsing: Parsing,
+}
+
+impl Solver for Day01 {
+    type Input = Lists;
+
+    fn name(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+    fn day(&self) -> u8 {
+        1
+    }
+    fn variant(&self) -> &'static str {
+        match self.parsing {
+            Parsing::Grow => "grow",
+            Parsing::Preallocate => "preallocate",
+        }
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        match self.parsing {
+            Parsing::Grow => {
+                let (mut list_1, mut list_2, occurrences) = parse_input_vector_increase(input)?;
+                list_1.sort();
+                list_2.sort();
+                Ok((list_1, list_2, occurrences))
+            }
+            Parsing::Preallocate => parse_input_preallocate(input),
+        }
+    }
+    fn part1(&self, (list_1, list_2, _): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(calculate_distance(list_1, list_2).into())
+    }
+    fn part2(&self, (list_1, _, occurrences): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(calculate_similarity(list_1, occurrences).into())
+    }
+}
+
+crate::register_solvers!(
+    Day01 {
+        parsing: Parsing::Preallocate
+    },
+    Day01 {
+        parsing: Parsing::Grow
+    }
+);
+
+// Parse the input line by line and have the vectors grow with each line
+// Should be faster for small inputs
+pub fn parse_input_vector_increase(input_data: &str) -> Result<Lists, SolveError> {
+    let mut list_1 = Vec::new();
+    let mut list_2 = Vec::new();
+    let mut occurrences: HashMap<i64, i64> = HashMap::new();
+
+    for line in input_data.lines() {
+        let (first, second) = parse_line(input_data, line)?;
+        list_1.push(first);
+        list_2.push(second);
+        *occurrences.entry(second).or_insert(0) += 1;
+    }
+    Ok((list_1, list_2, occurrences))
+}
+
+// Parse the input first and preallocate the vectors
+// Should be faster for larger inputs
+pub fn parse_input_preallocate(input_data: &str) -> Result<Lists, SolveError> {
+    let lines: Vec<&str> = input_data.lines().collect();
+    let mut list_1 = vec![0; lines.len()];
+    let mut list_2 = vec![0; lines.len()];
+    let mut occurrences: HashMap<i64, i64> = HashMap::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        let (first, second) = parse_line(input_data, line)?;
+        list_1[index] = first;
+        list_2[index] = second;
+        *occurrences.entry(second).or_insert(0) += 1;
+    }
+    list_1.sort();
+    list_2.sort();
+    Ok((list_1, list_2, occurrences))
+}
+
+// Both location IDs of a line, separated by three spaces
+fn parse_line(input_data: &str, line: &str) -> Result<(i64, i64), SolveError> {
+    let Some((first, second)) = line.split_once("   ") else {
+        return Err(SolveError::at(
+            input_data,
+            line,
+            "expected two numbers separated by three spaces",
+        ));
+    };
+    Ok((parse_at(input_data, first)?, parse_at(input_data, second)?))
+}
+
+fn calculate_distance(list_1: &[i64], list_2: &[i64]) -> i64 {
+    list_1
+        .iter()
+        .zip(list_2)
+        .map(|(first, second)| (first - second).abs())
+        .sum()
+}
+
+fn calculate_similarity(list_1: &[i64], occurrences: &HashMap<i64, i64>) -> i64 {
+    let mut similarity_score = 0;
+
+    list_1.iter().for_each(|&x| {
+        if let Some(&count) = occurrences.get(&x) {
+            similarity_score += x * count;
+        }
+    });
+
+    similarity_score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day01_invalid_input() {
+        let input = "3   4\n4 3\n2   x";
+        for parsing in [Parsing::Grow, Parsing::Preallocate] {
+            let error = Day01 { parsing }.parse(input).err();
+            assert!(matches!(error, Some(SolveError::Parse { line: 2, .. })));
+        }
+        let error = Day01 {
+            parsing: Parsing::Grow,
+        }
+        .parse("3   4\n2   x");
+        assert!(matches!(
+            error,
+            Err(SolveError::Parse {
+                line: 2,
+                column: 5,
+                ..
+            })
+        ));
+    }
+}
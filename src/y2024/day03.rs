@@ -8,35 +8,90 @@ use rayon::prelude::*;
 
 use regex::Regex;
 
-// Find all occurrences of `mul(n,m)` where n and m are integers
+use crate::{answer::Answer, error::SolveError, solver::Solver};
+
+// Find all occurrences of `mul(n,m)` where n and m are integers with 1-3 digits
 // and we return the integers n and m as captured groups
-static MUL_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"mul\((\d+),(\d+)\)").unwrap());
+static MUL_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap());
+
+// The different ways of running part 2, registered as variants so they can be benchmarked
+#[derive(Debug, Clone, Copy)]
+pub enum Strategy {
+    Parallel,
+    Sequential,
+    ManualMultithreading,
+}
+
+pub struct Day03 {
+    strategy: Strategy,
+}
 
-pub fn solve(filename: &String) -> (i64, i64) {
-    let input = std::fs::read_to_string(filename).expect("Could not read file!");
-    day_03(&input)
+impl Solver for Day03 {
+    type Input = String;
+
+    fn name(&self) -> &'static str {
+        "Mull It Over"
+    }
+    fn day(&self) -> u8 {
+        3
+    }
+    fn variant(&self) -> &'static str {
+        match self.strategy {
+            Strategy::Parallel => "parallel",
+            Strategy::Sequential => "sequential",
+            Strategy::ManualMultithreading => "manual_multithreading",
+        }
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.to_string())
+    }
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(calculate_sum_of_mul(input).into())
+    }
+    fn part2(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+        let sum = match self.strategy {
+            Strategy::Parallel => calculate_conditional_sum_of_mul(input),
+            Strategy::Sequential => calculate_conditional_sum_of_mul_sequentially(input),
+            Strategy::ManualMultithreading => {
+                calculate_conditional_sum_of_mul_manual_multithreading(input)
+            }
+        };
+        Ok(sum.into())
+    }
 }
 
-fn calculate_sum_of_mul(input_data: &String) -> i64 {
+crate::register_solvers!(
+    Day03 {
+        strategy: Strategy::Parallel
+    },
+    Day03 {
+        strategy: Strategy::Sequential
+    },
+    Day03 {
+        strategy: Strategy::ManualMultithreading
+    }
+);
+
+fn calculate_sum_of_mul(input_data: &str) -> i64 {
     // This lets the function sleep for 1 ms to simulate a slow calculation
     // uncomment to try it out
     // thread::sleep(std::time::Duration::from_millis(1));
 
     MUL_REGEX
         .captures_iter(input_data)
-        .filter_map(|cap| {
-            // The captures contains the 2 blocks of digits
+        .map(|cap| {
+            // The captures contains the 2 blocks of at most 3 digits, so they always fit
             let first = cap[1].parse::<i64>().unwrap();
             let second = cap[2].parse::<i64>().unwrap();
 
-            Some(first * second)
+            first * second
         })
         .sum()
 }
 
 // Find all substrings between `do()` and `don't()` where we assume that
 // we start with a `do()` sequence.
-fn get_substrings(input_data: &String) -> Vec<String> {
+fn get_substrings(input_data: &str) -> Vec<String> {
     let mut do_substrings = Vec::new();
 
     let mut start_index = 0;
@@ -71,7 +126,7 @@ fn get_substrings(input_data: &String) -> Vec<String> {
 
 // Manually create threads and run each substring in a separate thread
 // Use a mutex to lock the sum variable and Arc to share it between threads
-fn calculate_conditional_sum_of_mul_manual_multithreading(input_data: &String) -> i64 {
+fn calculate_conditional_sum_of_mul_manual_multithreading(input_data: &str) -> i64 {
     let do_substrings = get_substrings(input_data);
 
     let sum = Arc::new(Mutex::new(0));
@@ -96,7 +151,7 @@ fn calculate_conditional_sum_of_mul_manual_multithreading(input_data: &String) -
 }
 
 // Use rayon to parallelize the calculation of the sum
-fn calculate_conditional_sum_of_mul(input_data: &String) -> i64 {
+fn calculate_conditional_sum_of_mul(input_data: &str) -> i64 {
     let do_substrings = get_substrings(input_data);
 
     do_substrings
@@ -107,79 +162,8 @@ fn calculate_conditional_sum_of_mul(input_data: &String) -> i64 {
 }
 
 // Simple sequential calculation of the sum of products
-fn calculate_conditional_sum_of_mul_sequentially(input_data: &String) -> i64 {
+fn calculate_conditional_sum_of_mul_sequentially(input_data: &str) -> i64 {
     let do_substrings = get_substrings(input_data);
 
     do_substrings.iter().map(|s| calculate_sum_of_mul(s)).sum()
 }
-
-fn day_03(input_data: &String) -> (i64, i64) {
-    let result_1 = calculate_sum_of_mul(input_data);
-
-    let result_2 = calculate_conditional_sum_of_mul(input_data);
-
-    (result_1, result_2)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    extern crate test;
-    use test::Bencher;
-
-    #[test]
-    fn test_day03() {
-        let result_1 = 161;
-        let result_2 = 48;
-        let input1 =
-            "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))".to_string();
-        let input2 =
-            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))".to_string();
-        let output_1 = calculate_sum_of_mul(&input1);
-        let output_2 = calculate_conditional_sum_of_mul(&input2);
-        assert_eq!(result_1, output_1);
-        assert_eq!(result_2, output_2);
-    }
-
-    #[bench]
-    fn bench_day03_calc_sum_of_mul(b: &mut Bencher) {
-        let filename = "data/day03.txt";
-        let input = std::fs::read_to_string(filename).expect("Could not read file!");
-        b.iter(|| {
-            calculate_sum_of_mul(&input);
-        });
-    }
-
-    #[bench]
-    fn bench_day03_substrings_parallel(b: &mut Bencher) {
-        let filename = "data/day03.txt";
-        let input = std::fs::read_to_string(filename).expect("Could not read file!");
-        MUL_REGEX.as_str();
-
-        b.iter(|| {
-            calculate_conditional_sum_of_mul(&input);
-        });
-    }
-
-    #[bench]
-    fn bench_day03_substrings_sequentially(b: &mut Bencher) {
-        let filename = "data/day03.txt";
-        let input = std::fs::read_to_string(filename).expect("Could not read file!");
-        MUL_REGEX.as_str();
-
-        b.iter(|| {
-            calculate_conditional_sum_of_mul_sequentially(&input);
-        });
-    }
-
-    #[bench]
-    fn bench_day03_substrings_test_manual_implementation(b: &mut Bencher) {
-        let filename = "data/day03.txt";
-        let input = std::fs::read_to_string(filename).expect("Could not read file!");
-        MUL_REGEX.as_str();
-
-        b.iter(|| {
-            calculate_conditional_sum_of_mul_manual_multithreading(&input);
-        });
-    }
-}
@@ -0,0 +1,69 @@
+use crate::{
+    answer::Answer,
+    error::SolveError,
+    shared_objects::{Direction8, Grid, Position},
+    solver::Solver,
+};
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Grid<char>;
+
+    fn name(&self) -> &'static str {
+        "Ceres Search"
+    }
+    fn day(&self) -> u8 {
+        4
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Grid::parse(input, Ok::<char, String>)
+    }
+    fn part1(&self, data: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(data
+            .enumerate()
+            .filter(|(_, &letter)| letter == 'X')
+            .map(|(position, _)| find_xmas(data, &position))
+            .sum::<i64>()
+            .into())
+    }
+    fn part2(&self, data: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(data
+            .enumerate()
+            .filter(|(position, &letter)| letter == 'A' && find_x_mas(data, position))
+            .count()
+            .into())
+    }
+}
+
+crate::register_solvers!(Day04);
+
+// Starting from an X, find the word "XMAS" in all eight directions
+fn find_xmas(data: &Grid<char>, position: &Position) -> i64 {
+    Direction8::ALL
+        .iter()
+        .filter(|&&direction| {
+            // We start on X so we can skip the first letter
+            let mut next_position = *position;
+            "MAS".chars().all(|letter| {
+                next_position = next_position + direction;
+                data.get(&next_position) == Some(&letter)
+            })
+        })
+        .count() as i64
+}
+
+// Starting from an A, finding MAS on both diagonals, in either direction
+fn find_x_mas(data: &Grid<char>, position: &Position) -> bool {
+    let corner = |direction| data.get(&(*position + direction)).copied();
+
+    // Only a match if we have a match in both diagonals
+    [Direction8::UpLeft, Direction8::UpRight]
+        .iter()
+        .all(|&direction| {
+            matches!(
+                (corner(direction), corner(direction.opposite())),
+                (Some('M'), Some('S')) | (Some('S'), Some('M'))
+            )
+        })
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use crate::{
+    answer::Answer,
+    error::{parse_at, SolveError},
+    solver::Solver,
+};
+use rayon::prelude::*;
+
+pub struct PrintQueue {
+    // We list all the pages which must follow a certain page as a hashmap of vectors
+    rules_map: HashMap<i64, Vec<i64>>,
+    print_orders: Vec<Vec<i64>>,
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = PrintQueue;
+
+    fn name(&self) -> &'static str {
+        "Print Queue"
+    }
+    fn day(&self) -> u8 {
+        5
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        // Split input into the rules and the print orders
+        let rules = input
+            .lines()
+            .take_while(|s| !s.is_empty())
+            .map(|s| match s.split_once("|") {
+                Some((before, after)) => Ok((parse_at(input, before)?, parse_at(input, after)?)),
+                None => Err(SolveError::at(input, s, "expected a rule like `47|53`")),
+            })
+            .collect::<Result<Vec<(i64, i64)>, SolveError>>()?;
+
+        let mut rules_map: HashMap<i64, Vec<i64>> = HashMap::new();
+        rules.iter().for_each(|(before, after)| {
+            rules_map.entry(*before).or_default().push(*after);
+        });
+
+        let print_orders = input
+            .lines()
+            .skip(rules.len() + 1)
+            .map(|s| {
+                s.split(",")
+                    .map(|s| parse_at(input, s))
+                    .collect::<Result<Vec<i64>, SolveError>>()
+            })
+            .collect::<Result<Vec<Vec<i64>>, SolveError>>()?;
+
+        Ok(PrintQueue {
+            rules_map,
+            print_orders,
+        })
+    }
+    fn part1(&self, queue: &Self::Input) -> Result<Answer, SolveError> {
+        // This is way to cheap to parallelize
+        Ok(queue
+            .print_orders
+            .iter()
+            .filter(|po| is_valid_print_order(po, &queue.rules_map))
+            .map(|po| po[(po.len() - 1) / 2])
+            .sum::<i64>()
+            .into())
+    }
+    fn part2(&self, queue: &Self::Input) -> Result<Answer, SolveError> {
+        // The fixing is quite expensive, as it goes through the print orders multiple times
+        // so this is the perfect candidate for parallelization
+        Ok(queue
+            .print_orders
+            .par_iter()
+            .filter(|po| !is_valid_print_order(po, &queue.rules_map))
+            .map(|po| fix_print_order(po, &queue.rules_map))
+            .map(|po| po[(po.len() - 1) / 2])
+            .sum::<i64>()
+            .into())
+    }
+}
+
+crate::register_solvers!(Day05);
+
+fn is_valid_print_order(print_order: &[i64], rules_map: &HashMap<i64, Vec<i64>>) -> bool {
+    let mut valid = true;
+    'outer: for i in 0..print_order.len() {
+        if let Some(must_update_after) = rules_map.get(&print_order[i]) {
+            for following in &print_order[i + 1..] {
+                // all following numbers must be in the rules list
+                if !must_update_after.contains(following) {
+                    valid = false;
+                    break 'outer;
+                }
+            }
+            for previous in &print_order[..i] {
+                // all previous numbers must not be in the rules list
+                if must_update_after.contains(previous) {
+                    valid = false;
+                    break 'outer;
+                }
+            }
+        }
+    }
+
+    valid
+}
+
+fn fix_print_order(print_order: &[i64], rules_map: &HashMap<i64, Vec<i64>>) -> Vec<i64> {
+    let mut fixed_print_order = print_order.to_vec();
+    'fixing: loop {
+        for i in 0..fixed_print_order.len() {
+            if let Some(must_update_after) = rules_map.get(&fixed_print_order[i]) {
+                for j in 0..i {
+                    // if the order is not correct, we swap the elements until it is :-)
+                    if must_update_after.contains(&fixed_print_order[j]) {
+                        fixed_print_order.swap(i, j);
+                        continue 'fixing;
+                    }
+                }
+            }
+        }
+        break;
+    }
+
+    fixed_print_order
+}
@@ -0,0 +1,108 @@
+use rayon::prelude::*;
+use std::{collections::HashSet, fmt};
+
+use crate::shared_objects::{Direction, Position, SparseGrid};
+use crate::{answer::Answer, error::SolveError, solver::Solver};
+
+pub struct Lab {
+    sparse_grid: SparseGrid,
+    initial_position: Position,
+    empty_tiles: HashSet<Position>,
+}
+
+// The lab with the guard at its start, for debugging
+impl fmt::Display for Lab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.sparse_grid
+            .render()
+            .cursor(self.initial_position, Direction::Up, None)
+            .fmt(f)
+    }
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = Lab;
+
+    fn name(&self) -> &'static str {
+        "Guard Gallivant"
+    }
+    fn day(&self) -> u8 {
+        6
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let (sparse_grid, guards) = SparseGrid::parse(input, &['.'], &['^'])?;
+        let Some(&(initial_position, _)) = guards.first() else {
+            return Err(SolveError::at(input, input, "no guard `^` found"));
+        };
+        // New obstacles can be placed anywhere except on the obstacles and the guard
+        let empty_tiles = (0..sparse_grid.max_row)
+            .flat_map(|y| (0..sparse_grid.max_col).map(move |x| Position { x, y }))
+            .filter(|pos| sparse_grid.is_free_tile(pos) && *pos != initial_position)
+            .collect();
+
+        Ok(Lab {
+            sparse_grid,
+            initial_position,
+            empty_tiles,
+        })
+    }
+    fn part1(&self, lab: &Self::Input) -> Result<Answer, SolveError> {
+        match guard_walk(&lab.sparse_grid, &lab.initial_position) {
+            Some(visited) => Ok(visited.into()),
+            None => Err(SolveError::unsolvable("the guard never leaves the lab")),
+        }
+    }
+    fn part2(&self, lab: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(lab
+            .empty_tiles
+            .par_iter()
+            .map(|new_obstacle| {
+                let mut new_sparse_grid = lab.sparse_grid.clone();
+                new_sparse_grid.set(*new_obstacle, '#');
+                // println!("New obstacle at {:?}", new_obstacle);
+                guard_walk(&new_sparse_grid, &lab.initial_position)
+            })
+            .filter(|x| x.is_none())
+            .count()
+            .into())
+    }
+}
+
+crate::register_solvers!(Day06);
+
+fn guard_walk(sparse_grid: &SparseGrid, initial_position: &Position) -> Option<i64> {
+    let mut position = *initial_position;
+    let mut direction = Direction::Up;
+    let mut visited_positions_and_directions: HashSet<(Position, Direction)> = HashSet::new();
+
+    loop {
+        // Store the current position and direction as visited
+
+        let mut next_position = position;
+        while sparse_grid.is_free_tile(&next_position) {
+            // If we have been here before, we are stuck in a loop
+            if visited_positions_and_directions.contains(&(next_position, direction)) {
+                return None;
+            }
+            visited_positions_and_directions.insert((next_position, direction));
+            position = next_position;
+            next_position = next_position + direction;
+        }
+
+        if !sparse_grid.is_inside(&next_position) {
+            break;
+        }
+
+        direction = direction.turn_right();
+    }
+
+    Some(
+        visited_positions_and_directions
+            .iter()
+            .map(|(p, _)| p)
+            .collect::<HashSet<_>>()
+            .len() as i64,
+    )
+}
@@ -0,0 +1,150 @@
+use rayon::prelude::*;
+
+use crate::{
+    answer::Answer,
+    error::{parse_at, SolveError},
+    solver::Solver,
+};
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input = Vec<(i64, Vec<i64>)>;
+
+    fn name(&self) -> &'static str {
+        "Bridge Repair"
+    }
+    fn day(&self) -> u8 {
+        7
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .lines()
+            .map(|line| parse_input_string(input, line))
+            .collect()
+    }
+    fn part1(&self, combinations: &Self::Input) -> Result<Answer, SolveError> {
+        let validity = check_for_valid_combination(combinations, calculate_tree);
+        Ok(get_total_sum(combinations, &validity).into())
+    }
+    fn part2(&self, combinations: &Self::Input) -> Result<Answer, SolveError> {
+        let validity = check_for_valid_combination(combinations, calculate_tree_part_2);
+        Ok(get_total_sum(combinations, &validity).into())
+    }
+}
+
+crate::register_solvers!(Day07);
+
+fn parse_input_string(input: &str, input_string: &str) -> Result<(i64, Vec<i64>), SolveError> {
+    let Some((target_value, values)) = input_string.split_once(":") else {
+        return Err(SolveError::at(
+            input,
+            input_string,
+            "expected an equation like `190: 10 19`",
+        ));
+    };
+    let target_value = parse_at(input, target_value)?;
+    let values = values
+        .split_whitespace()
+        .map(|s| parse_at(input, s))
+        .collect::<Result<Vec<i64>, SolveError>>()?;
+    if values.is_empty() {
+        return Err(SolveError::at(
+            input,
+            input_string,
+            "equation without values",
+        ));
+    }
+
+    Ok((target_value, values))
+}
+
+fn calculate_tree(previous_values: &[i64], value: i64, limit: i64) -> Vec<i64> {
+    let mut result = vec![];
+    previous_values.iter().for_each(|&p| {
+        let new_values = [p.checked_mul(value), p.checked_add(value)];
+        result.extend(new_values.into_iter().flatten().filter(|&v| v <= limit));
+    });
+
+    result
+}
+
+fn calculate_tree_part_2(previous_values: &[i64], value: i64, limit: i64) -> Vec<i64> {
+    let mut result = vec![];
+    previous_values.iter().for_each(|&p| {
+        let new_values = [p.checked_mul(value), p.checked_add(value), concat(p, value)];
+        result.extend(new_values.into_iter().flatten().filter(|&v| v <= limit));
+    });
+
+    result
+}
+
+// Appends the digits of `value` to `p`, e.g. 12 and 345 give 12345. None on overflow or if
+// `value` is negative.
+fn concat(p: i64, value: i64) -> Option<i64> {
+    if value < 0 {
+        return None;
+    }
+    let digits = value.checked_ilog10().unwrap_or(0) + 1;
+    p.checked_mul(10_i64.checked_pow(digits)?)?
+        .checked_add(value)
+}
+
+fn check_for_valid_combination(
+    combinations: &[(i64, Vec<i64>)],
+    calculate_function: fn(&[i64], i64, i64) -> Vec<i64>,
+) -> Vec<bool> {
+    combinations
+        .par_iter()
+        .map(|(target_value, values)| {
+            // With positive values the operators never make a result smaller, so results above
+            // the target can be dropped. Results which overflow are always dropped.
+            let limit = if values.iter().all(|&v| v > 0) {
+                *target_value
+            } else {
+                i64::MAX
+            };
+            let mut sums = vec![values[0]];
+            values.iter().skip(1).for_each(|v| {
+                sums = calculate_function(&sums, *v, limit);
+            });
+            sums.contains(target_value)
+        })
+        .collect()
+}
+
+fn get_total_sum(combinations: &[(i64, Vec<i64>)], validity: &[bool]) -> i64 {
+    combinations
+        .iter()
+        .zip(validity)
+        .map(
+            |((target_value, _), valid)| {
+                if *valid {
+                    *target_value
+                } else {
+                    0
+                }
+            },
+        )
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day07_overflow() {
+        assert_eq!(Some(12345), concat(12, 345));
+        assert_eq!(Some(120), concat(12, 0));
+        assert_eq!(None, concat(i64::MAX / 10, 99));
+
+        let equations = Day07
+            .parse("1234567890123: 999999 999999 999999 999999 9999")
+            .unwrap();
+        assert_eq!(Ok(Answer::from(0)), Day07.part2(&equations));
+        // Results above the target are only dropped if no value is zero
+        let equations = Day07.parse("5: 9 9 0 5").unwrap();
+        assert_eq!(Ok(Answer::from(5)), Day07.part1(&equations));
+    }
+}
@@ -1,16 +1,40 @@
 use std::collections::HashSet;
 
 use crate::{
-    my_io::read_input_to_vector,
+    answer::Answer,
+    error::SolveError,
     shared_objects::{Position, SparseGrid},
+    solver::Solver,
 };
 use prime_factorization::Factorization;
 
-pub fn solve(filename: &String) -> (i64, i64) {
-    let input = read_input_to_vector(filename);
-    day_08(&input)
+pub struct Day08;
+
+impl Solver for Day08 {
+    // The grid plus the different antenna types
+    type Input = (SparseGrid, Vec<char>);
+
+    fn name(&self) -> &'static str {
+        "Resonant Collinearity"
+    }
+    fn day(&self) -> u8 {
+        8
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let (sparse_grid, _) = SparseGrid::parse(input, &['.'], &[])?;
+        let unique_entries = sparse_grid.get_unique_entries();
+        Ok((sparse_grid, unique_entries))
+    }
+    fn part1(&self, (sparse_grid, unique_entries): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(get_2x_antinodes(sparse_grid, unique_entries).into())
+    }
+    fn part2(&self, (sparse_grid, unique_entries): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(get_1x_antinodes(sparse_grid, unique_entries).into())
+    }
 }
 
+crate::register_solvers!(Day08);
+
 fn get_antinodes_with_2x_distance(
     sparse_grid: &SparseGrid,
     antenna_1: &Position,
@@ -33,8 +57,8 @@ fn get_antinodes_with_2x_distance(
 /// Calculates the shortest distance vector that wil always end on a grid entry by removing the
 /// common factors e.g. for a distance vector of (2, -4) that would be (1, -2)
 fn get_shortest_distance_vector_on_grid(distance_vector: &Position) -> Position {
-    let x_factors = Factorization::<u32>::run(distance_vector.x.abs() as u32);
-    let y_factors = Factorization::<u32>::run(distance_vector.y.abs() as u32);
+    let x_factors = Factorization::<u32>::run(distance_vector.x.unsigned_abs());
+    let y_factors = Factorization::<u32>::run(distance_vector.y.unsigned_abs());
 
     // We add 1 as factor so if x_factors and y_factors are identical and to keep
     // the sign of the distance vector
@@ -45,7 +69,7 @@ fn get_shortest_distance_vector_on_grid(distance_vector: &Position) -> Position
         .iter()
         .map(|x| *x as i32)
         .collect();
-    new_y_factors.push(1 * distance_vector.y.signum());
+    new_y_factors.push(distance_vector.y.signum());
 
     x_factors.factors.iter().for_each(|factor| {
         let factor = *factor as i32;
@@ -92,7 +116,7 @@ fn get_antinodes_with_1x_distance(
     antinodes
 }
 
-fn get_2x_antinodes(sparse_grid: &SparseGrid, unique_entries: &Vec<char>) -> i64 {
+fn get_2x_antinodes(sparse_grid: &SparseGrid, unique_entries: &[char]) -> i64 {
     // we do not want to count the same antinode twice
     let mut antinodes = HashSet::new();
     unique_entries.iter().for_each(|entry| {
@@ -100,7 +124,7 @@ fn get_2x_antinodes(sparse_grid: &SparseGrid, unique_entries: &Vec<char>) -> i64
         for i in 0..positions.len() {
             for j in i + 1..positions.len() {
                 let local_antinodes =
-                    get_antinodes_with_2x_distance(&sparse_grid, &positions[i], &positions[j]);
+                    get_antinodes_with_2x_distance(sparse_grid, &positions[i], &positions[j]);
                 antinodes.extend(local_antinodes);
             }
         }
@@ -109,7 +133,7 @@ fn get_2x_antinodes(sparse_grid: &SparseGrid, unique_entries: &Vec<char>) -> i64
     antinodes.len() as i64
 }
 
-fn get_1x_antinodes(sparse_grid: &SparseGrid, unique_entries: &Vec<char>) -> i64 {
+fn get_1x_antinodes(sparse_grid: &SparseGrid, unique_entries: &[char]) -> i64 {
     // we do not want to count the same antinode twice
     let mut antinodes = HashSet::new();
     unique_entries.iter().for_each(|entry| {
@@ -119,7 +143,7 @@ fn get_1x_antinodes(sparse_grid: &SparseGrid, unique_entries: &Vec<char>) -> i64
         for i in 0..positions.len() {
             for j in i + 1..positions.len() {
                 let local_antinodes =
-                    get_antinodes_with_1x_distance(&sparse_grid, &positions[i], &positions[j]);
+                    get_antinodes_with_1x_distance(sparse_grid, &positions[i], &positions[j]);
                 antinodes.extend(local_antinodes);
             }
         }
@@ -127,64 +151,3 @@ fn get_1x_antinodes(sparse_grid: &SparseGrid, unique_entries: &Vec<char>) -> i64
 
     antinodes.len() as i64
 }
-
-fn day_08(input_data: &Vec<String>) -> (i64, i64) {
-    let result_1;
-    let result_2;
-
-    let input_chars = input_data
-        .iter()
-        .map(|s| s.chars().collect())
-        .collect::<Vec<Vec<char>>>();
-
-    let sparse_grid = SparseGrid::from_input_data(&input_chars);
-
-    // Different antenna types
-    let unique_entries = sparse_grid.get_unique_entries();
-
-    result_1 = get_2x_antinodes(&sparse_grid, &unique_entries);
-
-    result_2 = get_1x_antinodes(&sparse_grid, &unique_entries);
-
-    (result_1, result_2)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::my_io::read_input_to_vector;
-    extern crate test;
-    use test::Bencher;
-
-    #[test]
-    fn test_day08() {
-        let result_1 = 14;
-        let result_2 = 34;
-        let input = vec![
-            "............".to_string(),
-            "........0...".to_string(),
-            ".....0......".to_string(),
-            ".......0....".to_string(),
-            "....0.......".to_string(),
-            "......A.....".to_string(),
-            "............".to_string(),
-            "............".to_string(),
-            "........A...".to_string(),
-            ".........A..".to_string(),
-            "............".to_string(),
-            "............".to_string(),
-        ];
-        let (output_1, output_2) = day_08(&input);
-        assert_eq!(result_1, output_1);
-        assert_eq!(result_2, output_2);
-    }
-
-    #[bench]
-    fn bench_day08(b: &mut Bencher) {
-        let filename = "data/day08.txt";
-        let input = read_input_to_vector(filename);
-        b.iter(|| {
-            day_08(&input);
-        });
-    }
-}
@@ -1,4 +1,6 @@
-use std::collections::{BTreeMap, HashMap};
+use std::{collections::BTreeMap, fmt};
+
+use crate::{answer::Answer, error::SolveError, solver::Solver};
 
 #[derive(Debug, Clone, Copy)]
 struct FileInfo {
@@ -14,22 +16,22 @@ struct Disk {
 }
 
 impl Disk {
-    fn move_file(&mut self, file_id: usize, new_position: usize) {
-        let file_info = self.file_table.get(&file_id).unwrap();
+    fn move_file(&mut self, file_id: usize, new_position: usize) -> Result<(), SolveError> {
+        let file_info = self.file_info(file_id)?;
         let length = file_info.length;
         let old_position = file_info.position;
 
         if !self.empty_blocks.contains_key(&new_position) {
-            panic!(
-                "Position {} is not the beginning of an empty block",
+            return Err(SolveError::unsolvable(format!(
+                "position {} is not the beginning of an empty block",
                 new_position
-            );
+            )));
         }
         if self.empty_blocks[&new_position] < length {
-            panic!(
-                "Not enough space at position {} to move file {}",
+            return Err(SolveError::unsolvable(format!(
+                "not enough space at position {} to move file {}",
                 new_position, file_id
-            );
+            )));
         }
 
         for ii in 0..length {
@@ -51,39 +53,71 @@ impl Disk {
             );
         }
         self.empty_blocks.remove(&new_position);
+        Ok(())
     }
-    fn space_after(&self, file_id: usize) -> usize {
-        let this_file = self
-            .file_table
+    fn file_info(&self, file_id: usize) -> Result<FileInfo, SolveError> {
+        self.file_table
             .get(&file_id)
-            .unwrap_or_else(|| panic!("File {} not found", file_id));
+            .copied()
+            .ok_or_else(|| SolveError::unsolvable(format!("file {} not found", file_id)))
+    }
+    fn space_after(&self, file_id: usize) -> Result<usize, SolveError> {
+        let this_file = self.file_info(file_id)?;
 
-        *self
+        Ok(*self
             .empty_blocks
             .get(&(this_file.position + this_file.length))
-            .unwrap_or(&0)
+            .unwrap_or(&0))
     }
-    fn print_disk(&self) -> String {
-        self.data
+}
+
+// The blocks with their file ids and `.` for free blocks, for debugging
+impl fmt::Display for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let blocks = self
+            .data
             .iter()
-            .map(|&x| {
-                if x >= 0 {
-                    x.to_string()
-                } else {
-                    ".".to_string()
-                }
-            })
-            .collect::<Vec<_>>()
-            .join("")
-            + format!(" containing {} files", self.file_table.len()).as_str()
+            .map(|&x| if x >= 0 { x.to_string() } else { ".".into() })
+            .collect::<String>();
+        write!(f, "{} containing {} files", blocks, self.file_table.len())
     }
 }
 
-pub fn solve(filename: &String) -> (i64, i64) {
-    let input = std::fs::read_to_string(filename).expect("Could not read file!");
-    day_09(&input)
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Input = String;
+
+    fn name(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+    fn day(&self) -> u8 {
+        9
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let disk_map = input.trim_end();
+        if disk_map.is_empty() {
+            return Err(SolveError::at(input, input, "empty disk map"));
+        }
+        if let Some((index, _)) = disk_map.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(SolveError::at(
+                input,
+                &disk_map[index..],
+                "the disk map may only contain digits",
+            ));
+        }
+        Ok(disk_map.to_string())
+    }
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(calculate_checksum(&reorder_blocks(input)).into())
+    }
+    fn part2(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(calculate_checksum(&reorder_files(input)?).into())
+    }
 }
 
+crate::register_solvers!(Day09);
+
 fn calculate_checksum(disk: &Disk) -> i64 {
     disk.data
         .iter()
@@ -98,7 +132,7 @@ fn calculate_checksum(disk: &Disk) -> i64 {
         .sum()
 }
 
-fn unravel_disk(input_data: &String) -> Disk {
+fn unravel_disk(input_data: &str) -> Disk {
     let mut file_table = BTreeMap::new();
     let mut empty_blocks = BTreeMap::new();
 
@@ -106,7 +140,8 @@ fn unravel_disk(input_data: &String) -> Disk {
         .chars()
         .enumerate()
         .map(|(index, entry)| {
-            let value = entry.to_digit(10).unwrap() as usize;
+            // Only digits are left after parsing
+            let value = entry.to_digit(10).unwrap_or(0) as usize;
             if index % 2 == 0 {
                 let id = (index / 2) as i32;
                 vec![id; value]
@@ -139,10 +174,13 @@ fn unravel_disk(input_data: &String) -> Disk {
     }
 }
 
-fn reorder_blocks(input_data: &String) -> Disk {
+fn reorder_blocks(input_data: &str) -> Disk {
     let mut disk = unravel_disk(input_data);
     let data = &mut disk.data;
-    let mut back_pointer = data.len() - 1;
+    // Start at the last file block, without any file there is nothing to move
+    let Some(mut back_pointer) = data.iter().rposition(|&entry| entry >= 0) else {
+        return disk;
+    };
     let mut front_pointer = 0;
 
     'outer: loop {
@@ -167,12 +205,22 @@ fn reorder_blocks(input_data: &String) -> Disk {
     disk
 }
 
-fn reorder_files(input_data: &String) -> Disk {
+fn reorder_files(input_data: &str) -> Result<Disk, SolveError> {
     let mut disk = unravel_disk(input_data);
 
-    for file_id in (0..disk.file_table.len()).rev() {
-        let file_info = disk.file_table.get(&file_id).unwrap();
-        let length = file_info.length;
+    // Files of length zero are not in the file table, so the ids may have gaps
+    let file_ids = disk.file_table.keys().rev().copied().collect::<Vec<_>>();
+    for file_id in file_ids {
+        let length = disk.file_info(file_id)?.length;
+        // Free space at the very beginning is not after any file, there is some if file 0 is empty
+        if disk
+            .empty_blocks
+            .get(&0)
+            .is_some_and(|&space| space >= length)
+        {
+            disk.move_file(file_id, 0)?;
+            continue;
+        }
         let mut front_pointer = 0;
         loop {
             while front_pointer < disk.data.len()
@@ -186,10 +234,10 @@ fn reorder_files(input_data: &String) -> Disk {
             }
 
             let file_id_at_pointer = disk.data[front_pointer] as usize;
-            let file_at_pointer = disk.file_table.get(&file_id_at_pointer).unwrap();
+            let file_at_pointer = disk.file_info(file_id_at_pointer)?;
 
-            if disk.space_after(file_id_at_pointer) >= length {
-                disk.move_file(file_id, file_at_pointer.position + file_at_pointer.length);
+            if disk.space_after(file_id_at_pointer)? >= length {
+                disk.move_file(file_id, file_at_pointer.position + file_at_pointer.length)?;
                 break;
             } else {
                 front_pointer = file_at_pointer.position + file_at_pointer.length;
@@ -197,43 +245,31 @@ fn reorder_files(input_data: &String) -> Disk {
         }
     }
 
-    disk
-}
-
-fn day_09(input_data: &String) -> (i64, i64) {
-    let disk = reorder_blocks(input_data);
-
-    let checksum_1 = calculate_checksum(&disk);
-
-    let disk = reorder_files(input_data);
-
-    let checksum_2 = calculate_checksum(&disk);
-
-    (checksum_1, checksum_2)
+    Ok(disk)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    extern crate test;
-    use test::Bencher;
 
     #[test]
-    fn test_day09() {
-        let result_1 = 1928;
-        let result_2 = 2858;
-        let input = "2333133121414131402".to_string();
-        let (output_1, output_2) = day_09(&input);
-        assert_eq!(result_1, output_1);
-        assert_eq!(result_2, output_2);
+    fn test_day09_zero_length_files() {
+        for input in ["0", "00", "02", "0203", "0211"] {
+            let disk_map = Day09.parse(input).unwrap();
+            assert_eq!(Ok(Answer::from(0)), Day09.part1(&disk_map));
+            assert_eq!(Ok(Answer::from(0)), Day09.part2(&disk_map));
+        }
+        assert_eq!(
+            Ok(Answer::from(3)),
+            Day09.part1(&Day09.parse("102").unwrap())
+        );
     }
 
-    #[bench]
-    fn bench_day09(b: &mut Bencher) {
-        let filename = "data/day09.txt";
-        let input = std::fs::read_to_string(filename).expect("Could not read file!");
-        b.iter(|| {
-            day_09(&input);
-        });
+    #[test]
+    fn test_disk_display() {
+        assert_eq!(
+            "0..111.. containing 2 files",
+            unravel_disk("1232").to_string()
+        );
     }
 }
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use crate::{
+    answer::Answer,
+    error::SolveError,
+    shared_objects::{Connectivity, Grid, Position},
+    solver::Solver,
+};
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    // The height map and all trailheads
+    type Input = (Grid<u8>, HashSet<Position>);
+
+    fn name(&self) -> &'static str {
+        "Hoof It"
+    }
+    fn day(&self) -> u8 {
+        10
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let grid = Grid::parse(input, |c| {
+            c.to_digit(10)
+                .map(|height| height as u8)
+                .ok_or("heights must be digits")
+        })?;
+
+        let start_positions = grid
+            .enumerate()
+            .filter(|(_, &height)| height == 0)
+            .map(|(position, _)| position)
+            .collect();
+        Ok((grid, start_positions))
+    }
+    fn part1(&self, (grid, start_positions): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(start_positions
+            .iter()
+            .map(|pos| {
+                let mut peaks: HashSet<Position> = HashSet::new();
+                ascend(grid, &mut peaks, pos)
+            })
+            .sum::<i64>()
+            .into())
+    }
+    fn part2(&self, (grid, start_positions): &Self::Input) -> Result<Answer, SolveError> {
+        Ok(start_positions
+            .iter()
+            .map(|pos| ascend_unbound(grid, pos))
+            .sum::<i64>()
+            .into())
+    }
+}
+
+crate::register_solvers!(Day10);
+
+fn ascend(grid: &Grid<u8>, visited_peaks: &mut HashSet<Position>, position: &Position) -> i64 {
+    if visited_peaks.contains(position) {
+        return 0;
+    }
+
+    if let Some(&current_height) = grid.get(position) {
+        if current_height == 9 {
+            visited_peaks.insert(*position);
+            1
+        } else {
+            grid.neighbors(position, Connectivity::Four)
+                .map(|(new_position, &height)| {
+                    if height == current_height + 1 {
+                        ascend(grid, visited_peaks, &new_position)
+                    } else {
+                        0
+                    }
+                })
+                .sum()
+        }
+    } else {
+        0
+    }
+}
+
+fn ascend_unbound(grid: &Grid<u8>, position: &Position) -> i64 {
+    if let Some(&current_height) = grid.get(position) {
+        if current_height == 9 {
+            1
+        } else {
+            grid.neighbors(position, Connectivity::Four)
+                .map(|(new_position, &height)| {
+                    if height == current_height + 1 {
+                        ascend_unbound(grid, &new_position)
+                    } else {
+                        0
+                    }
+                })
+                .sum()
+        }
+    } else {
+        0
+    }
+}
@@ -0,0 +1,90 @@
+use rayon::iter::{ParallelBridge, ParallelIterator};
+
+use crate::{
+    answer::Answer,
+    error::{parse_at, SolveError},
+    solver::Solver,
+};
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input = Vec<String>;
+
+    fn name(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+    fn day(&self) -> u8 {
+        11
+    }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .split_whitespace()
+            .map(|x| parse_at::<u64>(input, x).map(|stone| stone.to_string()))
+            .collect()
+    }
+    fn part1(&self, first_row: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(blink_n_times(first_row, 25)?.len().into())
+    }
+    fn part2(&self, first_row: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(blink_n_times(first_row, 75)?.len().into())
+    }
+}
+
+crate::register_solvers!(Day11);
+
+fn blink(input_row: &[String]) -> Result<Vec<String>, SolveError> {
+    let mut result = Vec::new();
+    for row in input_row {
+        let stone = parse_stone(row)?;
+        if stone == 0 {
+            result.push("1".to_string());
+        } else if (row.len() % 2) == 0 {
+            let (left_half, right_half) = row.split_at(row.len() / 2);
+            result.push(parse_stone(left_half)?.to_string());
+            result.push(parse_stone(right_half)?.to_string());
+        } else {
+            let Some(new_stone) = stone.checked_mul(2024) else {
+                return Err(SolveError::unsolvable(format!(
+                    "stone {} is too large to be multiplied by 2024",
+                    stone
+                )));
+            };
+            result.push(new_stone.to_string());
+        }
+    }
+    Ok(result)
+}
+
+fn parse_stone(stone: &str) -> Result<u64, SolveError> {
+    stone
+        .parse()
+        .map_err(|_| SolveError::unsolvable(format!("invalid stone {}", stone)))
+}
+
+fn parallel_blink(
+    input_row: &[String],
+    subset_size: usize,
+    minimum_split_number: usize,
+) -> Result<Vec<String>, SolveError> {
+    if input_row.len() <= minimum_split_number * subset_size {
+        return blink(input_row);
+    }
+
+    let chunks = input_row.chunks(subset_size);
+    let results = chunks
+        .par_bridge()
+        .map(blink)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(results.concat())
+}
+
+fn blink_n_times(input_row: &[String], n: u64) -> Result<Vec<String>, SolveError> {
+    let mut result = input_row.to_vec();
+    for _ in 0..n {
+        // Parallelize the blinking
+        result = parallel_blink(&result, 3000, 5)?;
+    }
+    Ok(result)
+}
@@ -0,0 +1,58 @@
+// Runs the examples in `examples/` and the personal inputs in `data/` against all registered
+// variants of their day. Inputs which are not available are skipped, so the tests pass on a
+// fresh clone without any puzzle inputs.
+use std::path::Path;
+
+use advent2024::{
+    answers::AnswerStore,
+    fixtures::{self, Fixture, EXAMPLES_DIR},
+    registry,
+    runner::ANSWERS_FILE,
+};
+
+// Checks every fixture which can be loaded and returns all failures
+fn check_all(fixtures: &[Fixture]) -> Vec<String> {
+    let mut failures = vec![];
+    for fixture in fixtures {
+        let input = match fixture.load() {
+            Ok(input) => input,
+            Err(skipped) => {
+                println!("{}", skipped);
+                continue;
+            }
+        };
+        let solvers = registry()
+            .iter_variants()
+            .filter(|s| s.id() == fixture.day)
+            .collect::<Vec<_>>();
+        if solvers.is_empty() {
+            failures.push(format!("{}: no solver registered", fixture.day));
+        }
+        for solver in solvers {
+            failures.extend(fixture.check(solver, &input));
+        }
+    }
+    failures
+}
+
+#[test]
+fn test_examples() {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(EXAMPLES_DIR);
+    let failures = check_all(&fixtures::discover(&examples_dir).unwrap());
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+// Only the parts with a confirmed answer in `data/answers.toml` are checked
+#[test]
+fn test_real_inputs() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let answers = AnswerStore::load(root.join(ANSWERS_FILE)).unwrap();
+    let days = registry().iter().map(|s| s.id()).collect::<Vec<_>>();
+    let fixtures = fixtures::real_inputs(
+        &root.join("data"),
+        &answers,
+        days.iter().map(|d| d.as_str()),
+    );
+    let failures = check_all(&fixtures);
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}